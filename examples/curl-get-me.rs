@@ -0,0 +1,11 @@
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let access_token = std::env::var("ACCESS_TOKEN")?;
+    let client = hyper_traq::Client::builder()
+        .authorization_bearer(&access_token)
+        .build();
+    let req = hyper_traq::apis::me::GetMe::new();
+    let command = client.curl_command(&req, true).await?;
+    println!("{}", command);
+    Ok(())
+}
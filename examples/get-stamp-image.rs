@@ -0,0 +1,27 @@
+use hyper_traq::apis::stamps::GetStampImage;
+use uuid::Uuid;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let access_token = std::env::var("ACCESS_TOKEN")?;
+    let client = hyper_traq::Client::builder()
+        .authorization_bearer(&access_token)
+        .build();
+    let args: Vec<String> = std::env::args().collect();
+    let id: Uuid = args
+        .get(1)
+        .ok_or("must provide a UUID as an argument")?
+        .parse()?;
+    let req = GetStampImage::new(id);
+    let res = client.request(req).await?;
+    let extension = match res.mime.as_str() {
+        "image/gif" => "gif",
+        "image/jpeg" => "jpg",
+        "image/svg+xml" => "svg",
+        _ => "png",
+    };
+    let filename = format!("{}.{}", id, extension);
+    std::fs::write(&filename, &res.data)?;
+    println!("saved image as {}", filename);
+    Ok(())
+}
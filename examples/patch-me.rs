@@ -0,0 +1,21 @@
+use hyper_traq::apis::me::PatchMe;
+use hyper_traq::models::PatchMeRequest;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let access_token = std::env::var("ACCESS_TOKEN")?;
+    let client = hyper_traq::Client::builder()
+        .authorization_bearer(&access_token)
+        .build();
+    let args: Vec<String> = std::env::args().collect();
+    let bio = args.get(1).ok_or("must provide a bio as an argument")?;
+    let req = PatchMeRequest {
+        display_name: None,
+        twitter_id: None,
+        bio: Some(bio.clone()),
+        home_channel: None,
+    };
+    let req = PatchMe::new(req);
+    client.request(req).await?;
+    Ok(())
+}
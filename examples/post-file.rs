@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use hyper_traq::apis::files::PostFile;
+use image::ImageFormat;
+use uuid::Uuid;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let access_token = std::env::var("ACCESS_TOKEN")?;
+    let client = hyper_traq::Client::builder()
+        .authorization_bearer(&access_token)
+        .build();
+    let args: Vec<String> = std::env::args().collect();
+    let channel_id: Uuid = args
+        .get(1)
+        .ok_or("must provide a UUID as the 1st argument")?
+        .parse()?;
+    let path = args
+        .get(2)
+        .ok_or("must provide a file path as the 2nd argument")?;
+    let path = Path::new(path);
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or("file path must end with a UTF-8 file name")?;
+    // images are recognized by their extension, anything else needs an explicit MIME type
+    let mime = match args.get(3) {
+        Some(mime) => mime.as_str(),
+        None => ImageFormat::from_path(path)
+            .map_err(|_| "must provide a MIME type as the 3rd argument")?
+            .to_mime_type(),
+    };
+    let data = std::fs::read(path)?;
+    let req = PostFile::new(channel_id, name, mime, data.into());
+    let res = client.request(req).await?;
+    println!("{:?}", res);
+    Ok(())
+}
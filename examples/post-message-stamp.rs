@@ -0,0 +1,22 @@
+use hyper_traq::apis::messages::PostMessageStamp;
+use uuid::Uuid;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let access_token = std::env::var("ACCESS_TOKEN")?;
+    let client = hyper_traq::Client::builder()
+        .authorization_bearer(&access_token)
+        .build();
+    let args: Vec<String> = std::env::args().collect();
+    let message_id: Uuid = args
+        .get(1)
+        .ok_or("must provide a UUID as the 1st argument")?
+        .parse()?;
+    let stamp_id: Uuid = args
+        .get(2)
+        .ok_or("must provide a UUID as the 2nd argument")?
+        .parse()?;
+    let req = PostMessageStamp::new(message_id, stamp_id);
+    client.request(req).await?;
+    Ok(())
+}
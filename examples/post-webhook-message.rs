@@ -0,0 +1,20 @@
+use hyper_traq::apis::webhooks::PostWebhookMessage;
+use uuid::Uuid;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = hyper_traq::Client::builder().build();
+    let args: Vec<String> = std::env::args().collect();
+    let id: Uuid = args
+        .get(1)
+        .ok_or("must provide a UUID as an argument")?
+        .parse()?;
+    let req = PostWebhookMessage::new(id, "test");
+    let req = if let Ok(secret) = std::env::var("WEBHOOK_SECRET") {
+        req.secret(&secret)
+    } else {
+        req
+    };
+    client.request(req).await?;
+    Ok(())
+}
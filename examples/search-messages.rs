@@ -0,0 +1,22 @@
+use hyper_traq::apis::messages::{MessageSearchSort, SearchMessages};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let access_token = std::env::var("ACCESS_TOKEN")?;
+    let client = hyper_traq::Client::builder()
+        .authorization_bearer(&access_token)
+        .build();
+    let args: Vec<String> = std::env::args().collect();
+    let word = args.get(1).ok_or("must provide a word as an argument")?;
+    let req = SearchMessages::new()
+        .set_word(word)
+        .set_limit(20)
+        .set_sort(MessageSearchSort::CreatedAtDesc);
+    let mut pages = client.paginate(req);
+    while let Some(page) = pages.next_page().await {
+        for message in page? {
+            println!("{:?}", message);
+        }
+    }
+    Ok(())
+}
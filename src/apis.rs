@@ -4,8 +4,10 @@ use std::future::Future;
 use std::io::Error as IoError;
 use std::pin::Pin;
 use std::str::Utf8Error;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use hyper::body::Bytes;
+use hyper::http::response::Parts as ResponseParts;
 use hyper::http::StatusCode;
 use hyper::service::Service;
 use hyper::{Body, Method, Request};
@@ -15,8 +17,19 @@ use thiserror::Error as ThisError;
 use crate::auth::Authorization;
 use crate::client::Client;
 
+pub mod activity;
+pub mod bots;
+pub mod channels;
+pub mod clients;
+pub mod clips;
+pub mod files;
+pub mod groups;
 pub mod me;
+pub mod messages;
+pub mod stamp_palettes;
+pub mod stamps;
 pub mod users;
+pub mod webhooks;
 
 pub trait ApiRequest: Sync + Send + 'static {
     type Response: Sync + Send + 'static;
@@ -30,8 +43,24 @@ pub trait ApiRequest: Sync + Send + 'static {
     fn content_type(&self) -> Option<String> {
         None
     }
+    /// extra headers sent along with the request
+    fn headers(&self) -> Vec<(String, String)> {
+        vec![]
+    }
     fn body(&self) -> Body;
     fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error>;
+    /// builds the response from status, headers and body; by default non-success
+    /// statuses are reported as `Error::BadStatus` and the body is handed to `parse`
+    fn parse_response(
+        &self,
+        parts: &ResponseParts,
+        body: Bytes,
+    ) -> Result<Self::Response, Error<Self::Error>> {
+        if !parts.status.is_success() {
+            return Err(Error::BadStatus(parts.status, body));
+        }
+        self.parse(body).map_err(Error::Custom)
+    }
 }
 
 #[derive(Debug, ThisError)]
@@ -42,14 +71,16 @@ pub enum Error<T: StdError + Debug + Sync + Send + 'static> {
     HyperHttp(#[from] hyper::http::Error),
     #[error("bad status code: {0}")]
     BadStatus(StatusCode, Bytes),
+    #[error("request was recorded but not sent in dry-run mode")]
+    DryRun,
     #[error(transparent)]
     Custom(T),
 }
 
 impl Client {
-    pub async fn request<Req>(&self, req: Req) -> Result<Req::Response, Error<Req::Error>>
+    pub fn build_request<Req>(&self, req: &Req) -> Result<Request<Body>, hyper::http::Error>
     where
-        Req: ApiRequest + Clone + Sync + Send + 'static,
+        Req: ApiRequest,
     {
         use hyper::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
         let uri = format!("{}{}", &self.base_path, req.uri());
@@ -69,16 +100,26 @@ impl Client {
         } else {
             req_builder
         };
-        let raw_req = req_builder.body(req.body()).map_err(Error::HyperHttp)?;
+        let req_builder = req
+            .headers()
+            .into_iter()
+            .fold(req_builder, |b, (name, value)| b.header(name, value));
+        req_builder.body(req.body())
+    }
+
+    pub async fn request<Req>(&self, req: Req) -> Result<Req::Response, Error<Req::Error>>
+    where
+        Req: ApiRequest + Clone + Sync + Send + 'static,
+    {
+        let raw_req = self.build_request(&req).map_err(Error::HyperHttp)?;
+        if let Some(recorder) = &self.recorder {
+            recorder.record_request(raw_req).await?;
+            return Err(Error::DryRun);
+        }
         let res = self.inner.request(raw_req).await.map_err(Error::Hyper)?;
         let (parts, body) = res.into_parts();
-        let status = parts.status;
         let bytes = hyper::body::to_bytes(body).await.map_err(Error::Hyper)?;
-        if !status.is_success() {
-            Err(Error::BadStatus(status, bytes))
-        } else {
-            req.parse(bytes).map_err(Error::Custom)
-        }
+        req.parse_response(&parts, bytes)
     }
 }
 
@@ -103,6 +144,138 @@ where
     }
 }
 
+/// A request whose results are split into pages by `limit` and `offset`
+pub trait PaginatedRequest: ApiRequest + Clone {
+    type Item: Sync + Send + 'static;
+
+    fn offset(&self) -> u32;
+    fn with_offset(&self, offset: u32) -> Self;
+    fn items(response: Self::Response) -> Vec<Self::Item>;
+    /// total number of items across all pages, when the response reports it
+    fn total(_response: &Self::Response) -> Option<u64> {
+        None
+    }
+}
+
+/// Fetches successive pages of a `PaginatedRequest`, see `Client::paginate`
+#[derive(Debug, Clone)]
+pub struct Paginator<Req> {
+    client: Client,
+    next: Option<Req>,
+}
+
+impl<Req: PaginatedRequest> Paginator<Req> {
+    /// Returns `None` once a page comes back empty or the reported total is reached.
+    /// Short pages don't end the iteration, since traQ may cap `limit` below the requested value
+    pub async fn next_page(&mut self) -> Option<Result<Vec<Req::Item>, Error<Req::Error>>> {
+        let req = self.next.take()?;
+        let (items, total) = match self.client.request(req.clone()).await {
+            Ok(res) => {
+                let total = Req::total(&res);
+                (Req::items(res), total)
+            }
+            Err(e) => return Some(Err(e)),
+        };
+        if items.is_empty() {
+            return None;
+        }
+        let next_offset = req.offset() + items.len() as u32;
+        if total.map_or(true, |total| u64::from(next_offset) < total) {
+            self.next = Some(req.with_offset(next_offset));
+        }
+        Some(Ok(items))
+    }
+
+    pub async fn collect(mut self) -> Result<Vec<Req::Item>, Error<Req::Error>> {
+        let mut all = vec![];
+        while let Some(items) = self.next_page().await {
+            all.extend(items?);
+        }
+        Ok(all)
+    }
+}
+
+impl Client {
+    pub fn paginate<Req: PaginatedRequest>(&self, req: Req) -> Paginator<Req> {
+        Paginator {
+            client: self.clone(),
+            next: Some(req),
+        }
+    }
+}
+
+/// Percent-encodes `s` for use as a query parameter value
+pub(crate) fn encode_query_value(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Formats `t` as an RFC 3339 UTC timestamp with milliseconds, as traQ returns them
+pub(crate) fn format_timestamp(t: SystemTime) -> String {
+    let (secs, millis) = match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => (d.as_secs() as i64, d.subsec_millis()),
+        Err(e) => {
+            let d = e.duration();
+            match d.subsec_millis() {
+                0 => (-(d.as_secs() as i64), 0),
+                ms => (-(d.as_secs() as i64) - 1, 1000 - ms),
+            }
+        }
+    };
+    let (days, secs_of_day) = (secs.div_euclid(86400), secs.rem_euclid(86400));
+    // days since 1970-01-01 to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60,
+        millis
+    )
+}
+
+/// Serves `handler` on a local port and returns a client pointed at it
+#[cfg(test)]
+pub(crate) fn serve<F, Fut>(handler: F) -> Client
+where
+    F: Fn(Request<Body>) -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = Result<hyper::Response<Body>, std::convert::Infallible>> + Send + 'static,
+{
+    use std::convert::Infallible;
+    use std::net::SocketAddr;
+
+    use hyper::service::{make_service_fn, service_fn};
+    use hyper::Server;
+
+    let make_svc = make_service_fn(move |_| {
+        let handler = handler.clone();
+        async move { Ok::<_, Infallible>(service_fn(handler)) }
+    });
+    let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_svc);
+    let addr = server.local_addr();
+    tokio::spawn(server);
+    Client::builder()
+        .base_path(&format!("http://{}", addr))
+        .build()
+}
+
 #[derive(Debug, ThisError)]
 pub enum ApiError {
     #[error(transparent)]
@@ -114,3 +287,24 @@ pub enum ApiError {
     #[error(transparent)]
     Image(#[from] ImageError),
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn formats_timestamps() {
+        let at = |secs: u64, millis: u64| {
+            format_timestamp(UNIX_EPOCH + Duration::from_millis(secs * 1000 + millis))
+        };
+        assert_eq!(at(0, 0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(at(951_782_400, 5), "2000-02-29T00:00:00.005Z");
+        assert_eq!(at(1_704_067_199, 999), "2023-12-31T23:59:59.999Z");
+        assert_eq!(
+            format_timestamp(UNIX_EPOCH - Duration::from_millis(1)),
+            "1969-12-31T23:59:59.999Z"
+        );
+    }
+}
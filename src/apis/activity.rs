@@ -0,0 +1,125 @@
+use hyper::body::Bytes;
+use hyper::{Body, Method};
+use itertools::Itertools;
+use uuid::Uuid;
+
+use super::{ApiError, ApiRequest};
+use crate::models::ActivityTimelineMessages;
+
+/// `GET /activity/timeline`
+#[derive(Debug, Clone, Default)]
+pub struct GetActivityTimeline {
+    limit: Option<u32>,
+    all: Option<bool>,
+    per_channel: Option<bool>,
+}
+
+impl GetActivityTimeline {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_limit(self, limit: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// includes channels the user does not subscribe to
+    pub fn set_all(self, all: bool) -> Self {
+        Self {
+            all: Some(all),
+            ..self
+        }
+    }
+
+    /// returns at most one message per channel
+    pub fn set_per_channel(self, per_channel: bool) -> Self {
+        Self {
+            per_channel: Some(per_channel),
+            ..self
+        }
+    }
+}
+
+impl ApiRequest for GetActivityTimeline {
+    type Response = ActivityTimelineMessages;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        let mut query: Vec<(&str, String)> = vec![];
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(all) = self.all {
+            query.push(("all", all.to_string()));
+        }
+        if let Some(per_channel) = self.per_channel {
+            query.push(("per_channel", per_channel.to_string()));
+        }
+        if query.is_empty() {
+            return "/activity/timeline".to_string();
+        }
+        let query = query
+            .into_iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .join("&");
+        format!("/activity/timeline?{}", query)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /activity/onlines`
+#[derive(Debug, Clone, Default)]
+pub struct GetOnlineUsers;
+
+impl GetOnlineUsers {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetOnlineUsers {
+    type Response = Vec<Uuid>;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/activity/onlines".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
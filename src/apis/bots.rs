@@ -0,0 +1,581 @@
+use std::convert::Infallible;
+use std::io::Cursor;
+
+use hyper::body::Bytes;
+use hyper::{Body, Method};
+use itertools::Itertools;
+use uuid::Uuid;
+
+use super::{ApiError, ApiRequest, PaginatedRequest};
+use crate::models::{
+    BotDetail, BotEventLog, BotEventLogs, BotTokens, Bots, Image, PatchBotRequest,
+    PostBotActionJoinRequest, PostBotActionLeaveRequest, PostBotRequest,
+};
+use crate::multipart::Multipart;
+
+/// `GET /bots`
+#[derive(Debug, Clone, Default)]
+pub struct GetBots {
+    all: bool,
+}
+
+impl GetBots {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// includes bots developed by other users
+    pub fn all(self, all: bool) -> Self {
+        Self { all }
+    }
+}
+
+impl ApiRequest for GetBots {
+    type Response = Bots;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        if self.all {
+            return "/bots?all=true".to_string();
+        }
+        "/bots".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /bots/{id}?detail=true`
+#[derive(Debug, Clone)]
+pub struct GetBot {
+    id: Uuid,
+}
+
+impl GetBot {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetBot {
+    type Response = BotDetail;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/bots/{}?detail=true", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /bots`
+#[derive(Debug, Clone)]
+pub struct PostBot {
+    request: PostBotRequest,
+}
+
+impl PostBot {
+    pub fn new(request: PostBotRequest) -> Self {
+        Self { request }
+    }
+}
+
+impl ApiRequest for PostBot {
+    type Response = BotDetail;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/bots".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostBotRequest")
+            .into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PATCH /bots/{id}`
+#[derive(Debug, Clone)]
+pub struct PatchBot {
+    id: Uuid,
+    request: PatchBotRequest,
+}
+
+impl PatchBot {
+    pub fn new(id: Uuid, request: PatchBotRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for PatchBot {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/bots/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchBotRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /bots/{id}`
+#[derive(Debug, Clone)]
+pub struct DeleteBot {
+    id: Uuid,
+}
+
+impl DeleteBot {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for DeleteBot {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/bots/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `POST /bots/{id}/actions/activate`
+#[derive(Debug, Clone)]
+pub struct ActivateBot {
+    id: Uuid,
+}
+
+impl ActivateBot {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for ActivateBot {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/bots/{}/actions/activate", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `POST /bots/{id}/actions/inactivate`
+#[derive(Debug, Clone)]
+pub struct InactivateBot {
+    id: Uuid,
+}
+
+impl InactivateBot {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for InactivateBot {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/bots/{}/actions/inactivate", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `POST /bots/{id}/actions/reissue`
+#[derive(Debug, Clone)]
+pub struct ReissueBot {
+    id: Uuid,
+}
+
+impl ReissueBot {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for ReissueBot {
+    type Response = BotTokens;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/bots/{}/actions/reissue", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /bots/{id}/actions/join`
+#[derive(Debug, Clone)]
+pub struct LetBotJoinChannel {
+    id: Uuid,
+    request: PostBotActionJoinRequest,
+}
+
+impl LetBotJoinChannel {
+    pub fn new(id: Uuid, request: PostBotActionJoinRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for LetBotJoinChannel {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/bots/{}/actions/join", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostBotActionJoinRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `POST /bots/{id}/actions/leave`
+#[derive(Debug, Clone)]
+pub struct LetBotLeaveChannel {
+    id: Uuid,
+    request: PostBotActionLeaveRequest,
+}
+
+impl LetBotLeaveChannel {
+    pub fn new(id: Uuid, request: PostBotActionLeaveRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for LetBotLeaveChannel {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/bots/{}/actions/leave", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostBotActionLeaveRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /bots/{id}/logs`
+#[derive(Debug, Clone)]
+pub struct GetBotLogs {
+    id: Uuid,
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+impl GetBotLogs {
+    pub fn new(id: Uuid) -> Self {
+        Self {
+            id,
+            limit: None,
+            offset: None,
+        }
+    }
+
+    pub fn set_limit(self, limit: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    pub fn set_offset(self, offset: u32) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+}
+
+impl ApiRequest for GetBotLogs {
+    type Response = BotEventLogs;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        let mut query: Vec<(&str, String)> = vec![];
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            query.push(("offset", offset.to_string()));
+        }
+        let s = format!("/bots/{}/logs", self.id);
+        if query.is_empty() {
+            return s;
+        }
+        let query = query
+            .into_iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .join("&");
+        format!("{}?{}", s, query)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+impl PaginatedRequest for GetBotLogs {
+    type Item = BotEventLog;
+
+    fn offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+
+    fn with_offset(&self, offset: u32) -> Self {
+        self.clone().set_offset(offset)
+    }
+
+    fn items(response: Self::Response) -> Vec<Self::Item> {
+        response
+    }
+}
+
+/// `GET /bots/{id}/icon`
+#[derive(Debug, Clone)]
+pub struct GetBotIcon {
+    id: Uuid,
+}
+
+impl GetBotIcon {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetBotIcon {
+    type Response = Image;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/bots/{}/icon", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("image/jpeg, image/png, image/gif".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        use image::io::Reader as ImageReader;
+
+        let cursor = Cursor::new(body);
+        let img = ImageReader::new(cursor).with_guessed_format()?.decode()?;
+        Ok(img)
+    }
+}
+
+/// `PUT /bots/{id}/icon`
+#[derive(Debug, Clone)]
+pub struct PutBotIcon {
+    id: Uuid,
+    mime: String,
+    data: Bytes,
+}
+
+impl PutBotIcon {
+    pub fn new(id: Uuid, mime: &str, data: Bytes) -> Self {
+        Self {
+            id,
+            mime: mime.to_string(),
+            data,
+        }
+    }
+
+    fn form(&self) -> Multipart<'_> {
+        Multipart::new().file("file", "icon", &self.mime, &self.data)
+    }
+}
+
+impl ApiRequest for PutBotIcon {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/bots/{}/icon", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some(self.form().content_type())
+    }
+
+    fn body(&self) -> Body {
+        self.form().finish().into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
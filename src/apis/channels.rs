@@ -0,0 +1,862 @@
+use std::convert::Infallible;
+
+use hyper::body::Bytes;
+use hyper::{Body, Method};
+use itertools::Itertools;
+use uuid::Uuid;
+
+use super::{encode_query_value, ApiError, ApiRequest, PaginatedRequest};
+use crate::models::{
+    BotUsers, Channel, ChannelEvent, ChannelEvents, ChannelList, ChannelStats, ChannelTopic,
+    ChannelViewers, Message, Messages, PatchChannelRequest, PatchChannelSubscribersRequest, Pins,
+    PostChannelRequest, PostMessageRequest, PutChannelSubscribersRequest, PutChannelTopicRequest,
+};
+
+/// `GET /channels`
+#[derive(Debug, Clone, Default)]
+pub struct GetChannels {
+    include_dm: bool,
+}
+
+impl GetChannels {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn include_dm(self, include_dm: bool) -> Self {
+        Self { include_dm }
+    }
+}
+
+impl ApiRequest for GetChannels {
+    type Response = ChannelList;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        if self.include_dm {
+            return "/channels?include-dm=true".to_string();
+        }
+        "/channels".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /channels/{id}`
+#[derive(Debug, Clone)]
+pub struct GetChannel {
+    id: Uuid,
+}
+
+impl GetChannel {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetChannel {
+    type Response = Channel;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /channels`
+#[derive(Debug, Clone)]
+pub struct PostChannel {
+    request: PostChannelRequest,
+}
+
+impl PostChannel {
+    pub fn new(request: PostChannelRequest) -> Self {
+        Self { request }
+    }
+}
+
+impl ApiRequest for PostChannel {
+    type Response = Channel;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/channels".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostChannelRequest")
+            .into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PATCH /channels/{id}`
+#[derive(Debug, Clone)]
+pub struct PatchChannel {
+    id: Uuid,
+    request: PatchChannelRequest,
+}
+
+impl PatchChannel {
+    pub fn new(id: Uuid, request: PatchChannelRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for PatchChannel {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchChannelRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `POST /channels/{id}/messages`
+#[derive(Debug, Clone)]
+pub struct PostChannelMessage {
+    id: Uuid,
+    request: PostMessageRequest,
+}
+
+impl PostChannelMessage {
+    pub fn new(id: Uuid, request: PostMessageRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for PostChannelMessage {
+    type Response = Message;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}/messages", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostMessageRequest")
+            .into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /channels/{id}/messages`
+#[derive(Debug, Clone)]
+pub struct GetChannelMessages {
+    id: Uuid,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    since: Option<String>,
+    until: Option<String>,
+    inclusive: Option<bool>,
+    order: Option<String>,
+}
+
+impl GetChannelMessages {
+    pub fn new(id: Uuid) -> Self {
+        Self {
+            id,
+            limit: None,
+            offset: None,
+            since: None,
+            until: None,
+            inclusive: None,
+            order: None,
+        }
+    }
+
+    pub fn set_limit(self, limit: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    pub fn set_offset(self, offset: u32) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    pub fn set_since(self, since: &str) -> Self {
+        Self {
+            since: Some(since.to_string()),
+            ..self
+        }
+    }
+
+    pub fn set_until(self, until: &str) -> Self {
+        Self {
+            until: Some(until.to_string()),
+            ..self
+        }
+    }
+
+    pub fn set_inclusive(self, inclusive: bool) -> Self {
+        Self {
+            inclusive: Some(inclusive),
+            ..self
+        }
+    }
+
+    pub fn set_order(self, order: &str) -> Self {
+        Self {
+            order: Some(order.to_string()),
+            ..self
+        }
+    }
+}
+
+impl ApiRequest for GetChannelMessages {
+    type Response = Messages;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        let mut query: Vec<(&str, String)> = vec![];
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            query.push(("offset", offset.to_string()));
+        }
+        if let Some(since) = &self.since {
+            query.push(("since", encode_query_value(since)));
+        }
+        if let Some(until) = &self.until {
+            query.push(("until", encode_query_value(until)));
+        }
+        if let Some(inclusive) = self.inclusive {
+            query.push(("inclusive", inclusive.to_string()));
+        }
+        if let Some(order) = &self.order {
+            query.push(("order", order.clone()));
+        }
+        let s = format!("/channels/{}/messages", self.id);
+        if query.is_empty() {
+            return s;
+        }
+        let query = query
+            .into_iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .join("&");
+        format!("{}?{}", s, query)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+impl PaginatedRequest for GetChannelMessages {
+    type Item = Message;
+
+    fn offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+
+    fn with_offset(&self, offset: u32) -> Self {
+        self.clone().set_offset(offset)
+    }
+
+    fn items(response: Self::Response) -> Vec<Self::Item> {
+        response
+    }
+}
+
+/// `GET /channels/{id}/pins`
+#[derive(Debug, Clone)]
+pub struct GetChannelPins {
+    id: Uuid,
+}
+
+impl GetChannelPins {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetChannelPins {
+    type Response = Pins;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}/pins", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /channels/{id}/subscribers`
+#[derive(Debug, Clone)]
+pub struct GetChannelSubscribers {
+    id: Uuid,
+}
+
+impl GetChannelSubscribers {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetChannelSubscribers {
+    type Response = Vec<Uuid>;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}/subscribers", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PUT /channels/{id}/subscribers`
+#[derive(Debug, Clone)]
+pub struct SetChannelSubscribers {
+    id: Uuid,
+    request: PutChannelSubscribersRequest,
+}
+
+impl SetChannelSubscribers {
+    pub fn new(id: Uuid, request: PutChannelSubscribersRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for SetChannelSubscribers {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}/subscribers", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PutChannelSubscribersRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `PATCH /channels/{id}/subscribers`
+#[derive(Debug, Clone)]
+pub struct EditChannelSubscribers {
+    id: Uuid,
+    request: PatchChannelSubscribersRequest,
+}
+
+impl EditChannelSubscribers {
+    pub fn new(id: Uuid, request: PatchChannelSubscribersRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for EditChannelSubscribers {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}/subscribers", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchChannelSubscribersRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /channels/{id}/topic`
+#[derive(Debug, Clone)]
+pub struct GetChannelTopic {
+    id: Uuid,
+}
+
+impl GetChannelTopic {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetChannelTopic {
+    type Response = ChannelTopic;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}/topic", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PUT /channels/{id}/topic`
+#[derive(Debug, Clone)]
+pub struct EditChannelTopic {
+    id: Uuid,
+    request: PutChannelTopicRequest,
+}
+
+impl EditChannelTopic {
+    pub fn new(id: Uuid, topic: &str) -> Self {
+        let request = PutChannelTopicRequest {
+            topic: topic.to_string(),
+        };
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for EditChannelTopic {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}/topic", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PutChannelTopicRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /channels/{id}/stats`
+#[derive(Debug, Clone)]
+pub struct GetChannelStats {
+    id: Uuid,
+}
+
+impl GetChannelStats {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetChannelStats {
+    type Response = ChannelStats;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}/stats", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /channels/{id}/viewers`
+#[derive(Debug, Clone)]
+pub struct GetChannelViewers {
+    id: Uuid,
+}
+
+impl GetChannelViewers {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetChannelViewers {
+    type Response = ChannelViewers;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}/viewers", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /channels/{id}/bots`
+#[derive(Debug, Clone)]
+pub struct GetChannelBots {
+    id: Uuid,
+}
+
+impl GetChannelBots {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetChannelBots {
+    type Response = BotUsers;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/channels/{}/bots", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /channels/{id}/events`
+#[derive(Debug, Clone)]
+pub struct GetChannelEvents {
+    id: Uuid,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    since: Option<String>,
+    until: Option<String>,
+    inclusive: Option<bool>,
+    order: Option<String>,
+}
+
+impl GetChannelEvents {
+    pub fn new(id: Uuid) -> Self {
+        Self {
+            id,
+            limit: None,
+            offset: None,
+            since: None,
+            until: None,
+            inclusive: None,
+            order: None,
+        }
+    }
+
+    pub fn set_limit(self, limit: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    pub fn set_offset(self, offset: u32) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    pub fn set_since(self, since: &str) -> Self {
+        Self {
+            since: Some(since.to_string()),
+            ..self
+        }
+    }
+
+    pub fn set_until(self, until: &str) -> Self {
+        Self {
+            until: Some(until.to_string()),
+            ..self
+        }
+    }
+
+    pub fn set_inclusive(self, inclusive: bool) -> Self {
+        Self {
+            inclusive: Some(inclusive),
+            ..self
+        }
+    }
+
+    pub fn set_order(self, order: &str) -> Self {
+        Self {
+            order: Some(order.to_string()),
+            ..self
+        }
+    }
+}
+
+impl ApiRequest for GetChannelEvents {
+    type Response = ChannelEvents;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        let mut query: Vec<(&str, String)> = vec![];
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            query.push(("offset", offset.to_string()));
+        }
+        if let Some(since) = &self.since {
+            query.push(("since", encode_query_value(since)));
+        }
+        if let Some(until) = &self.until {
+            query.push(("until", encode_query_value(until)));
+        }
+        if let Some(inclusive) = self.inclusive {
+            query.push(("inclusive", inclusive.to_string()));
+        }
+        if let Some(order) = &self.order {
+            query.push(("order", order.clone()));
+        }
+        let s = format!("/channels/{}/events", self.id);
+        if query.is_empty() {
+            return s;
+        }
+        let query = query
+            .into_iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .join("&");
+        format!("{}?{}", s, query)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+impl PaginatedRequest for GetChannelEvents {
+    type Item = ChannelEvent;
+
+    fn offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+
+    fn with_offset(&self, offset: u32) -> Self {
+        self.clone().set_offset(offset)
+    }
+
+    fn items(response: Self::Response) -> Vec<Self::Item> {
+        response
+    }
+}
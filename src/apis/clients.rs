@@ -0,0 +1,247 @@
+use std::convert::Infallible;
+
+use hyper::body::Bytes;
+use hyper::{Body, Method};
+
+use super::{ApiError, ApiRequest};
+use crate::models::{OAuth2ClientDetail, OAuth2Clients, PatchClientRequest, PostClientRequest};
+
+/// `GET /clients`
+#[derive(Debug, Clone, Default)]
+pub struct GetClients {
+    all: bool,
+}
+
+impl GetClients {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// includes clients developed by other users
+    pub fn all(self, all: bool) -> Self {
+        Self { all }
+    }
+}
+
+impl ApiRequest for GetClients {
+    type Response = OAuth2Clients;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        if self.all {
+            return "/clients?all=true".to_string();
+        }
+        "/clients".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /clients/{id}?detail=true`
+#[derive(Debug, Clone)]
+pub struct GetClient {
+    id: String,
+}
+
+impl GetClient {
+    pub fn new(id: &str) -> Self {
+        Self { id: id.to_string() }
+    }
+}
+
+impl ApiRequest for GetClient {
+    type Response = OAuth2ClientDetail;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/clients/{}?detail=true", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /clients`
+#[derive(Debug, Clone)]
+pub struct CreateClient {
+    request: PostClientRequest,
+}
+
+impl CreateClient {
+    pub fn new(request: PostClientRequest) -> Self {
+        Self { request }
+    }
+}
+
+impl ApiRequest for CreateClient {
+    type Response = OAuth2ClientDetail;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/clients".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostClientRequest")
+            .into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PATCH /clients/{id}`
+#[derive(Debug, Clone)]
+pub struct EditClient {
+    id: String,
+    request: PatchClientRequest,
+}
+
+impl EditClient {
+    pub fn new(id: &str, request: PatchClientRequest) -> Self {
+        Self {
+            id: id.to_string(),
+            request,
+        }
+    }
+}
+
+impl ApiRequest for EditClient {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/clients/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchClientRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /clients/{id}`
+#[derive(Debug, Clone)]
+pub struct DeleteClient {
+    id: String,
+}
+
+impl DeleteClient {
+    pub fn new(id: &str) -> Self {
+        Self { id: id.to_string() }
+    }
+}
+
+impl ApiRequest for DeleteClient {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/clients/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /clients/{id}/tokens`
+#[derive(Debug, Clone)]
+pub struct RevokeClientTokens {
+    id: String,
+}
+
+impl RevokeClientTokens {
+    pub fn new(id: &str) -> Self {
+        Self { id: id.to_string() }
+    }
+}
+
+impl ApiRequest for RevokeClientTokens {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/clients/{}/tokens", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
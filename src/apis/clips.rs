@@ -0,0 +1,433 @@
+use std::convert::Infallible;
+
+use hyper::body::Bytes;
+use hyper::{Body, Method};
+use itertools::Itertools;
+use uuid::Uuid;
+
+use super::{ApiError, ApiRequest, PaginatedRequest};
+use crate::models::{
+    ClipFolder, ClipFolders, ClippedMessage, ClippedMessages, MessageClips, PatchClipFolderRequest,
+    PostClipFolderMessageRequest, PostClipFolderRequest,
+};
+
+/// `GET /clip-folders`
+#[derive(Debug, Clone, Default)]
+pub struct GetClipFolders;
+
+impl GetClipFolders {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetClipFolders {
+    type Response = ClipFolders;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/clip-folders".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /clip-folders/{id}`
+#[derive(Debug, Clone)]
+pub struct GetClipFolder {
+    id: Uuid,
+}
+
+impl GetClipFolder {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetClipFolder {
+    type Response = ClipFolder;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/clip-folders/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /clip-folders`
+#[derive(Debug, Clone)]
+pub struct PostClipFolder {
+    request: PostClipFolderRequest,
+}
+
+impl PostClipFolder {
+    pub fn new(request: PostClipFolderRequest) -> Self {
+        Self { request }
+    }
+}
+
+impl ApiRequest for PostClipFolder {
+    type Response = ClipFolder;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/clip-folders".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostClipFolderRequest")
+            .into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PATCH /clip-folders/{id}`
+#[derive(Debug, Clone)]
+pub struct PatchClipFolder {
+    id: Uuid,
+    request: PatchClipFolderRequest,
+}
+
+impl PatchClipFolder {
+    pub fn new(id: Uuid, request: PatchClipFolderRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for PatchClipFolder {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/clip-folders/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchClipFolderRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /clip-folders/{id}`
+#[derive(Debug, Clone)]
+pub struct DeleteClipFolder {
+    id: Uuid,
+}
+
+impl DeleteClipFolder {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for DeleteClipFolder {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/clip-folders/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /clip-folders/{id}/messages`
+#[derive(Debug, Clone)]
+pub struct GetClips {
+    id: Uuid,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    order: Option<String>,
+}
+
+impl GetClips {
+    pub fn new(id: Uuid) -> Self {
+        Self {
+            id,
+            limit: None,
+            offset: None,
+            order: None,
+        }
+    }
+
+    pub fn set_limit(self, limit: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    pub fn set_offset(self, offset: u32) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    pub fn set_order(self, order: &str) -> Self {
+        Self {
+            order: Some(order.to_string()),
+            ..self
+        }
+    }
+}
+
+impl ApiRequest for GetClips {
+    type Response = ClippedMessages;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        let mut query: Vec<(&str, String)> = vec![];
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            query.push(("offset", offset.to_string()));
+        }
+        if let Some(order) = &self.order {
+            query.push(("order", order.clone()));
+        }
+        let s = format!("/clip-folders/{}/messages", self.id);
+        if query.is_empty() {
+            return s;
+        }
+        let query = query
+            .into_iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .join("&");
+        format!("{}?{}", s, query)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+impl PaginatedRequest for GetClips {
+    type Item = ClippedMessage;
+
+    fn offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+
+    fn with_offset(&self, offset: u32) -> Self {
+        self.clone().set_offset(offset)
+    }
+
+    fn items(response: Self::Response) -> Vec<Self::Item> {
+        response
+    }
+}
+
+/// `POST /clip-folders/{id}/messages`
+#[derive(Debug, Clone)]
+pub struct ClipMessage {
+    id: Uuid,
+    request: PostClipFolderMessageRequest,
+}
+
+impl ClipMessage {
+    pub fn new(id: Uuid, request: PostClipFolderMessageRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for ClipMessage {
+    type Response = ClippedMessage;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/clip-folders/{}/messages", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostClipFolderMessageRequest")
+            .into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `DELETE /clip-folders/{folder_id}/messages/{message_id}`
+#[derive(Debug, Clone)]
+pub struct UnclipMessage {
+    folder_id: Uuid,
+    message_id: Uuid,
+}
+
+impl UnclipMessage {
+    pub fn new(folder_id: Uuid, message_id: Uuid) -> Self {
+        Self {
+            folder_id,
+            message_id,
+        }
+    }
+}
+
+impl ApiRequest for UnclipMessage {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!(
+            "/clip-folders/{}/messages/{}",
+            self.folder_id, self.message_id
+        )
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /messages/{id}/clips`
+#[derive(Debug, Clone)]
+pub struct GetMessageClips {
+    id: Uuid,
+}
+
+impl GetMessageClips {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetMessageClips {
+    type Response = MessageClips;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/messages/{}/clips", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
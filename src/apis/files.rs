@@ -0,0 +1,436 @@
+use std::convert::Infallible;
+
+use hyper::body::Bytes;
+use hyper::{Body, Method};
+use itertools::Itertools;
+use uuid::Uuid;
+
+use super::{encode_query_value, ApiError, ApiRequest, PaginatedRequest};
+use crate::models::{FileInfo, FileInfos, ThumbnailType};
+use crate::multipart::Multipart;
+
+/// `POST /files`
+#[derive(Debug, Clone)]
+pub struct PostFile {
+    channel_id: Uuid,
+    name: String,
+    mime: String,
+    data: Bytes,
+}
+
+impl PostFile {
+    pub fn new(channel_id: Uuid, name: &str, mime: &str, data: Bytes) -> Self {
+        Self {
+            channel_id,
+            name: name.to_string(),
+            mime: mime.to_string(),
+            data,
+        }
+    }
+
+    fn form(&self) -> Multipart<'_> {
+        Multipart::new()
+            .text("channelId", &self.channel_id.to_string())
+            .file("file", &self.name, &self.mime, &self.data)
+    }
+}
+
+impl ApiRequest for PostFile {
+    type Response = FileInfo;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/files".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some(self.form().content_type())
+    }
+
+    fn body(&self) -> Body {
+        self.form().finish().into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /files`
+#[derive(Debug, Clone, Default)]
+pub struct GetFiles {
+    channel_id: Option<Uuid>,
+    mine: Option<bool>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    since: Option<String>,
+    until: Option<String>,
+    inclusive: Option<bool>,
+    order: Option<String>,
+}
+
+impl GetFiles {
+    /// traQ requires either `set_channel_id` or `set_mine(true)`
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_channel_id(self, channel_id: Uuid) -> Self {
+        Self {
+            channel_id: Some(channel_id),
+            ..self
+        }
+    }
+
+    pub fn set_mine(self, mine: bool) -> Self {
+        Self {
+            mine: Some(mine),
+            ..self
+        }
+    }
+
+    pub fn set_limit(self, limit: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    pub fn set_offset(self, offset: u32) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    pub fn set_since(self, since: &str) -> Self {
+        Self {
+            since: Some(since.to_string()),
+            ..self
+        }
+    }
+
+    pub fn set_until(self, until: &str) -> Self {
+        Self {
+            until: Some(until.to_string()),
+            ..self
+        }
+    }
+
+    pub fn set_inclusive(self, inclusive: bool) -> Self {
+        Self {
+            inclusive: Some(inclusive),
+            ..self
+        }
+    }
+
+    pub fn set_order(self, order: &str) -> Self {
+        Self {
+            order: Some(order.to_string()),
+            ..self
+        }
+    }
+}
+
+impl ApiRequest for GetFiles {
+    type Response = FileInfos;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        let mut query: Vec<(&str, String)> = vec![];
+        if let Some(channel_id) = self.channel_id {
+            query.push(("channelId", channel_id.to_string()));
+        }
+        if let Some(mine) = self.mine {
+            query.push(("mine", mine.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            query.push(("offset", offset.to_string()));
+        }
+        if let Some(since) = &self.since {
+            query.push(("since", encode_query_value(since)));
+        }
+        if let Some(until) = &self.until {
+            query.push(("until", encode_query_value(until)));
+        }
+        if let Some(inclusive) = self.inclusive {
+            query.push(("inclusive", inclusive.to_string()));
+        }
+        if let Some(order) = &self.order {
+            query.push(("order", order.clone()));
+        }
+        if query.is_empty() {
+            return "/files".to_string();
+        }
+        let query = query
+            .into_iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .join("&");
+        format!("/files?{}", query)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+impl PaginatedRequest for GetFiles {
+    type Item = FileInfo;
+
+    fn offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+
+    fn with_offset(&self, offset: u32) -> Self {
+        self.clone().set_offset(offset)
+    }
+
+    fn items(response: Self::Response) -> Vec<Self::Item> {
+        response
+    }
+}
+
+/// `GET /files/{id}`
+#[derive(Debug, Clone)]
+pub struct GetFile {
+    id: Uuid,
+    dl: bool,
+}
+
+impl GetFile {
+    pub fn new(id: Uuid) -> Self {
+        Self { id, dl: false }
+    }
+
+    /// asks traQ to respond with `Content-Disposition: attachment`
+    pub fn dl(self, dl: bool) -> Self {
+        Self { dl, ..self }
+    }
+}
+
+impl ApiRequest for GetFile {
+    type Response = Bytes;
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        if self.dl {
+            return format!("/files/{}?dl=1", self.id);
+        }
+        format!("/files/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(body)
+    }
+}
+
+/// `GET /files/{id}/meta`
+#[derive(Debug, Clone)]
+pub struct GetFileMeta {
+    id: Uuid,
+}
+
+impl GetFileMeta {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetFileMeta {
+    type Response = FileInfo;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/files/{}/meta", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /files/{id}/thumbnail`
+///
+/// The raw thumbnail is returned, since waveform thumbnails are SVG
+/// and can't be decoded as an `Image`
+#[derive(Debug, Clone)]
+pub struct GetThumbnailImage {
+    id: Uuid,
+    thumbnail_type: Option<ThumbnailType>,
+}
+
+impl GetThumbnailImage {
+    pub fn new(id: Uuid) -> Self {
+        Self {
+            id,
+            thumbnail_type: None,
+        }
+    }
+
+    pub fn set_type(self, thumbnail_type: ThumbnailType) -> Self {
+        Self {
+            thumbnail_type: Some(thumbnail_type),
+            ..self
+        }
+    }
+}
+
+impl ApiRequest for GetThumbnailImage {
+    type Response = Bytes;
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        match self.thumbnail_type {
+            Some(thumbnail_type) => format!(
+                "/files/{}/thumbnail?type={}",
+                self.id,
+                thumbnail_type.as_str()
+            ),
+            None => format!("/files/{}/thumbnail", self.id),
+        }
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        match self.thumbnail_type {
+            Some(ThumbnailType::Waveform) => Some("image/svg+xml".to_string()),
+            _ => Some("image/png, image/webp".to_string()),
+        }
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(body)
+    }
+}
+
+/// `DELETE /files/{id}`
+#[derive(Debug, Clone)]
+pub struct DeleteFile {
+    id: Uuid,
+}
+
+impl DeleteFile {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for DeleteFile {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/files/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::header::CONTENT_TYPE;
+
+    use super::*;
+    use crate::apis::Error;
+    use crate::client::Client;
+
+    #[tokio::test]
+    async fn uploads_file_as_multipart() {
+        let client = Client::builder()
+            .base_path("http://traq.test/api/v3")
+            .dry_run(true)
+            .build();
+        let data = Bytes::from_static(b"\x00\x01binary\r\n");
+        let req = PostFile::new(Uuid::nil(), "a.bin", "application/x-binary", data);
+        assert!(matches!(client.request(req).await, Err(Error::DryRun)));
+
+        let recorded = client.recorded_requests().remove(0);
+        assert_eq!(recorded.uri.path(), "/api/v3/files");
+        let content_type = recorded.headers[CONTENT_TYPE].to_str().unwrap();
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+        let body = &recorded.body[..];
+        let expected_file = b"filename=\"a.bin\"\r\nContent-Type: application/x-binary\r\n\r\n\x00\x01binary\r\n\r\n";
+        assert!(body
+            .windows(expected_file.len())
+            .any(|w| w == expected_file));
+        assert!(body.ends_with(format!("--{}--\r\n", boundary).as_bytes()));
+    }
+
+    #[test]
+    fn thumbnail_type_in_query() {
+        let id = Uuid::nil();
+        let req = GetThumbnailImage::new(id);
+        assert_eq!(req.uri(), format!("/files/{}/thumbnail", id));
+        let req = req.set_type(ThumbnailType::Waveform);
+        assert_eq!(req.uri(), format!("/files/{}/thumbnail?type=waveform", id));
+        assert_eq!(req.accept().as_deref(), Some("image/svg+xml"));
+    }
+}
@@ -0,0 +1,597 @@
+use std::convert::Infallible;
+use std::io::Cursor;
+
+use hyper::body::Bytes;
+use hyper::{Body, Method};
+use uuid::Uuid;
+
+use super::{ApiError, ApiRequest};
+use crate::models::{
+    Image, PatchUserGroupMemberRequest, PatchUserGroupRequest, PostUserGroupAdminRequest,
+    PostUserGroupMemberRequest, PostUserGroupRequest, UserGroup, UserGroupMembers, UserGroups,
+};
+use crate::multipart::Multipart;
+
+/// `GET /groups`
+#[derive(Debug, Clone, Default)]
+pub struct GetUserGroups;
+
+impl GetUserGroups {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetUserGroups {
+    type Response = UserGroups;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/groups".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /groups/{id}`
+#[derive(Debug, Clone)]
+pub struct GetUserGroup {
+    id: Uuid,
+}
+
+impl GetUserGroup {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetUserGroup {
+    type Response = UserGroup;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/groups/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /groups`
+#[derive(Debug, Clone)]
+pub struct PostUserGroup {
+    request: PostUserGroupRequest,
+}
+
+impl PostUserGroup {
+    pub fn new(request: PostUserGroupRequest) -> Self {
+        Self { request }
+    }
+}
+
+impl ApiRequest for PostUserGroup {
+    type Response = UserGroup;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/groups".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostUserGroupRequest")
+            .into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PATCH /groups/{id}`
+#[derive(Debug, Clone)]
+pub struct PatchUserGroup {
+    id: Uuid,
+    request: PatchUserGroupRequest,
+}
+
+impl PatchUserGroup {
+    pub fn new(id: Uuid, request: PatchUserGroupRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for PatchUserGroup {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/groups/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchUserGroupRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /groups/{id}`
+#[derive(Debug, Clone)]
+pub struct DeleteUserGroup {
+    id: Uuid,
+}
+
+impl DeleteUserGroup {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for DeleteUserGroup {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/groups/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /groups/{id}/members`
+#[derive(Debug, Clone)]
+pub struct GetUserGroupMembers {
+    id: Uuid,
+}
+
+impl GetUserGroupMembers {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetUserGroupMembers {
+    type Response = UserGroupMembers;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/groups/{}/members", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /groups/{id}/members`
+#[derive(Debug, Clone)]
+pub struct AddUserGroupMember {
+    id: Uuid,
+    request: PostUserGroupMemberRequest,
+}
+
+impl AddUserGroupMember {
+    pub fn new(id: Uuid, request: PostUserGroupMemberRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for AddUserGroupMember {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/groups/{}/members", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostUserGroupMemberRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `PATCH /groups/{group_id}/members/{user_id}`
+#[derive(Debug, Clone)]
+pub struct EditUserGroupMember {
+    group_id: Uuid,
+    user_id: Uuid,
+    request: PatchUserGroupMemberRequest,
+}
+
+impl EditUserGroupMember {
+    pub fn new(group_id: Uuid, user_id: Uuid, request: PatchUserGroupMemberRequest) -> Self {
+        Self {
+            group_id,
+            user_id,
+            request,
+        }
+    }
+}
+
+impl ApiRequest for EditUserGroupMember {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/groups/{}/members/{}", self.group_id, self.user_id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchUserGroupMemberRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /groups/{group_id}/members/{user_id}`
+#[derive(Debug, Clone)]
+pub struct RemoveUserGroupMember {
+    group_id: Uuid,
+    user_id: Uuid,
+}
+
+impl RemoveUserGroupMember {
+    pub fn new(group_id: Uuid, user_id: Uuid) -> Self {
+        Self { group_id, user_id }
+    }
+}
+
+impl ApiRequest for RemoveUserGroupMember {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/groups/{}/members/{}", self.group_id, self.user_id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /groups/{id}/admins`
+#[derive(Debug, Clone)]
+pub struct GetUserGroupAdmins {
+    id: Uuid,
+}
+
+impl GetUserGroupAdmins {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetUserGroupAdmins {
+    type Response = Vec<Uuid>;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/groups/{}/admins", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /groups/{id}/admins`
+#[derive(Debug, Clone)]
+pub struct AddUserGroupAdmin {
+    id: Uuid,
+    request: PostUserGroupAdminRequest,
+}
+
+impl AddUserGroupAdmin {
+    pub fn new(id: Uuid, request: PostUserGroupAdminRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for AddUserGroupAdmin {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/groups/{}/admins", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostUserGroupAdminRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /groups/{group_id}/admins/{user_id}`
+#[derive(Debug, Clone)]
+pub struct RemoveUserGroupAdmin {
+    group_id: Uuid,
+    user_id: Uuid,
+}
+
+impl RemoveUserGroupAdmin {
+    pub fn new(group_id: Uuid, user_id: Uuid) -> Self {
+        Self { group_id, user_id }
+    }
+}
+
+impl ApiRequest for RemoveUserGroupAdmin {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/groups/{}/admins/{}", self.group_id, self.user_id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /files/{icon}`
+/// traQ has no dedicated endpoint for reading group icons,
+/// so this fetches the file referenced by `UserGroup::icon`
+#[derive(Debug, Clone)]
+pub struct GetUserGroupIcon {
+    icon_file_id: Uuid,
+}
+
+impl GetUserGroupIcon {
+    pub fn new(group: &UserGroup) -> Self {
+        Self::from_file(group.icon)
+    }
+
+    /// `icon_file_id` is the file id in `UserGroup::icon`, not the group id
+    pub fn from_file(icon_file_id: Uuid) -> Self {
+        Self { icon_file_id }
+    }
+}
+
+impl ApiRequest for GetUserGroupIcon {
+    type Response = Image;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/files/{}", self.icon_file_id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("image/jpeg, image/png, image/gif".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        use image::io::Reader as ImageReader;
+
+        let cursor = Cursor::new(body);
+        let img = ImageReader::new(cursor).with_guessed_format()?.decode()?;
+        Ok(img)
+    }
+}
+
+/// `PUT /groups/{id}/icon`
+#[derive(Debug, Clone)]
+pub struct PutUserGroupIcon {
+    id: Uuid,
+    mime: String,
+    data: Bytes,
+}
+
+impl PutUserGroupIcon {
+    pub fn new(id: Uuid, mime: &str, data: Bytes) -> Self {
+        Self {
+            id,
+            mime: mime.to_string(),
+            data,
+        }
+    }
+
+    fn form(&self) -> Multipart<'_> {
+        Multipart::new().file("file", "icon", &self.mime, &self.data)
+    }
+}
+
+impl ApiRequest for PutUserGroupIcon {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/groups/{}/icon", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some(self.form().content_type())
+    }
+
+    fn body(&self) -> Body {
+        self.form().finish().into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn icon_uri_uses_file_id() {
+        let group: UserGroup = serde_json::from_value(serde_json::json!({
+            "id": Uuid::from_u128(1),
+            "name": "group",
+            "description": "",
+            "type": "grade",
+            "icon": Uuid::from_u128(2),
+            "members": [],
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": "2024-01-01T00:00:00Z",
+            "admins": [],
+        }))
+        .unwrap();
+        let uri = format!("/files/{}", Uuid::from_u128(2));
+        assert_eq!(GetUserGroupIcon::new(&group).uri(), uri);
+        assert_eq!(GetUserGroupIcon::from_file(Uuid::from_u128(2)).uri(), uri);
+    }
+}
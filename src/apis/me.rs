@@ -1,10 +1,22 @@
+use std::convert::Infallible;
 use std::io::Cursor;
 
 use hyper::body::Bytes;
+use hyper::header::LOCATION;
+use hyper::http::response::Parts as ResponseParts;
 use hyper::{Body, Method};
+use thiserror::Error as ThisError;
+use uuid::Uuid;
 
-use super::{ApiError, ApiRequest};
-use crate::models::{MyUserDetail, QrCode, StampHistoryEntries};
+use super::{ApiError, ApiRequest, Error};
+use crate::models::{
+    ActiveOAuth2Tokens, ChannelSubscribeLevel, ExternalProviderUsers, LoginSessions,
+    MyChannelViewStates, MyUserDetail, OIDCUserInfo, PatchMeRequest, PatchUserTagRequest,
+    PostLinkExternalAccount, PostStarRequest, PostUnlinkExternalAccount, PostUserTagRequest,
+    PutChannelSubscribeLevelRequest, PutMyPasswordRequest, QrCode, StampHistoryEntries,
+    UnreadChannels, UserSubscribeStates, UserTag, UserTags,
+};
+use crate::multipart::Multipart;
 
 /// `GET /users/me/stamp-history`
 #[derive(Debug, Clone, Default)]
@@ -147,3 +159,958 @@ impl ApiRequest for GetMe {
         Ok(v)
     }
 }
+
+/// `GET /users/me/subscriptions`
+#[derive(Debug, Clone, Default)]
+pub struct GetMyChannelSubscriptions;
+
+impl GetMyChannelSubscriptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetMyChannelSubscriptions {
+    type Response = UserSubscribeStates;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/users/me/subscriptions".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PUT /users/me/subscriptions/{id}`
+#[derive(Debug, Clone)]
+pub struct SetChannelSubscribeLevel {
+    id: Uuid,
+    request: PutChannelSubscribeLevelRequest,
+}
+
+impl SetChannelSubscribeLevel {
+    pub fn new(id: Uuid, level: ChannelSubscribeLevel) -> Self {
+        let request = PutChannelSubscribeLevelRequest { level };
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for SetChannelSubscribeLevel {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/users/me/subscriptions/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PutChannelSubscribeLevelRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /users/me/unread`
+#[derive(Debug, Clone, Default)]
+pub struct GetMyUnreadChannels;
+
+impl GetMyUnreadChannels {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetMyUnreadChannels {
+    type Response = UnreadChannels;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/users/me/unread".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `DELETE /users/me/unread/{id}`
+#[derive(Debug, Clone)]
+pub struct ReadChannel {
+    id: Uuid,
+}
+
+impl ReadChannel {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for ReadChannel {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/users/me/unread/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /users/me/view-states`
+#[derive(Debug, Clone, Default)]
+pub struct GetMyViewStates;
+
+impl GetMyViewStates {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetMyViewStates {
+    type Response = MyChannelViewStates;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/users/me/view-states".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /users/me/stars`
+#[derive(Debug, Clone, Default)]
+pub struct GetMyStars;
+
+impl GetMyStars {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetMyStars {
+    type Response = Vec<Uuid>;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/users/me/stars".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /users/me/stars`
+#[derive(Debug, Clone)]
+pub struct AddMyStar {
+    request: PostStarRequest,
+}
+
+impl AddMyStar {
+    pub fn new(channel_id: Uuid) -> Self {
+        let request = PostStarRequest { channel_id };
+        Self { request }
+    }
+}
+
+impl ApiRequest for AddMyStar {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        "/users/me/stars".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostStarRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /users/me/stars/{id}`
+#[derive(Debug, Clone)]
+pub struct RemoveMyStar {
+    id: Uuid,
+}
+
+impl RemoveMyStar {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for RemoveMyStar {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/users/me/stars/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `PATCH /users/me`
+#[derive(Debug, Clone)]
+pub struct PatchMe {
+    request: PatchMeRequest,
+}
+
+impl PatchMe {
+    pub fn new(request: PatchMeRequest) -> Self {
+        Self { request }
+    }
+}
+
+impl ApiRequest for PatchMe {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        "/users/me".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchMeRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `PUT /users/me/icon`
+#[derive(Debug, Clone)]
+pub struct ChangeMyIcon {
+    mime: String,
+    data: Bytes,
+}
+
+impl ChangeMyIcon {
+    pub fn new(mime: &str, data: Bytes) -> Self {
+        Self {
+            mime: mime.to_string(),
+            data,
+        }
+    }
+
+    fn form(&self) -> Multipart<'_> {
+        Multipart::new().file("file", "icon", &self.mime, &self.data)
+    }
+}
+
+impl ApiRequest for ChangeMyIcon {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        "/users/me/icon".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some(self.form().content_type())
+    }
+
+    fn body(&self) -> Body {
+        self.form().finish().into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `PUT /users/me/password`
+#[derive(Debug, Clone)]
+pub struct ChangeMyPassword {
+    request: PutMyPasswordRequest,
+}
+
+impl ChangeMyPassword {
+    pub fn new(request: PutMyPasswordRequest) -> Self {
+        Self { request }
+    }
+}
+
+impl ApiRequest for ChangeMyPassword {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        "/users/me/password".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PutMyPasswordRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /users/me/tags`
+#[derive(Debug, Clone, Default)]
+pub struct GetMyUserTags;
+
+impl GetMyUserTags {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetMyUserTags {
+    type Response = UserTags;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/users/me/tags".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /users/me/tags`
+#[derive(Debug, Clone)]
+pub struct AddMyUserTag {
+    request: PostUserTagRequest,
+}
+
+impl AddMyUserTag {
+    pub fn new(request: PostUserTagRequest) -> Self {
+        Self { request }
+    }
+}
+
+impl ApiRequest for AddMyUserTag {
+    type Response = UserTag;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/users/me/tags".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostUserTagRequest")
+            .into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PATCH /users/me/tags/{id}`
+#[derive(Debug, Clone)]
+pub struct EditMyUserTag {
+    id: Uuid,
+    request: PatchUserTagRequest,
+}
+
+impl EditMyUserTag {
+    pub fn new(id: Uuid, request: PatchUserTagRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for EditMyUserTag {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/users/me/tags/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchUserTagRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /users/me/tags/{id}`
+#[derive(Debug, Clone)]
+pub struct RemoveMyUserTag {
+    id: Uuid,
+}
+
+impl RemoveMyUserTag {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for RemoveMyUserTag {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/users/me/tags/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /users/me/sessions`
+#[derive(Debug, Clone, Default)]
+pub struct GetMySessions;
+
+impl GetMySessions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetMySessions {
+    type Response = LoginSessions;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/users/me/sessions".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `DELETE /users/me/sessions/{id}`
+#[derive(Debug, Clone)]
+pub struct RevokeMySession {
+    id: Uuid,
+}
+
+impl RevokeMySession {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for RevokeMySession {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/users/me/sessions/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /users/me/tokens`
+#[derive(Debug, Clone, Default)]
+pub struct GetMyTokens;
+
+impl GetMyTokens {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetMyTokens {
+    type Response = ActiveOAuth2Tokens;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/users/me/tokens".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `DELETE /users/me/tokens/{id}`
+#[derive(Debug, Clone)]
+pub struct RevokeMyToken {
+    id: Uuid,
+}
+
+impl RevokeMyToken {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for RevokeMyToken {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/users/me/tokens/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /users/me/oidc`
+#[derive(Debug, Clone, Default)]
+pub struct GetOIDCUserInfo;
+
+impl GetOIDCUserInfo {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetOIDCUserInfo {
+    type Response = OIDCUserInfo;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/users/me/oidc".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /users/me/ex-accounts`
+#[derive(Debug, Clone, Default)]
+pub struct GetMyExternalAccounts;
+
+impl GetMyExternalAccounts {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetMyExternalAccounts {
+    type Response = ExternalProviderUsers;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/users/me/ex-accounts".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /users/me/ex-accounts/link`
+/// traQ answers with a redirect to the provider's authorization page,
+/// which has to be followed in the browser that owns the session.
+/// The response is that page's URL, taken from the `Location` header
+#[derive(Debug, Clone)]
+pub struct LinkExternalAccount {
+    request: PostLinkExternalAccount,
+}
+
+impl LinkExternalAccount {
+    pub fn new(provider_name: &str) -> Self {
+        let request = PostLinkExternalAccount {
+            provider_name: provider_name.to_string(),
+        };
+        Self { request }
+    }
+}
+
+/// `LinkExternalAccount` got no `Location` to redirect to
+#[derive(Debug, ThisError)]
+#[error("response has no Location header")]
+pub struct MissingLocation;
+
+impl ApiRequest for LinkExternalAccount {
+    type Response = String;
+    type Error = MissingLocation;
+
+    fn uri(&self) -> String {
+        "/users/me/ex-accounts/link".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostLinkExternalAccount")
+            .into()
+    }
+
+    /// the URL is only in the headers, see `parse_response`
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Err(MissingLocation)
+    }
+
+    fn parse_response(
+        &self,
+        parts: &ResponseParts,
+        body: Bytes,
+    ) -> Result<Self::Response, Error<Self::Error>> {
+        if !parts.status.is_redirection() {
+            return Err(Error::BadStatus(parts.status, body));
+        }
+        parts
+            .headers
+            .get(LOCATION)
+            .and_then(|l| l.to_str().ok())
+            .map(str::to_string)
+            .ok_or(Error::Custom(MissingLocation))
+    }
+}
+
+/// `POST /users/me/ex-accounts/unlink`
+#[derive(Debug, Clone)]
+pub struct UnlinkExternalAccount {
+    request: PostUnlinkExternalAccount,
+}
+
+impl UnlinkExternalAccount {
+    pub fn new(provider_name: &str) -> Self {
+        let request = PostUnlinkExternalAccount {
+            provider_name: provider_name.to_string(),
+        };
+        Self { request }
+    }
+}
+
+impl ApiRequest for UnlinkExternalAccount {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        "/users/me/ex-accounts/unlink".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostUnlinkExternalAccount")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::http::StatusCode;
+    use hyper::{Request, Response};
+
+    use super::*;
+    use crate::apis::serve;
+    use crate::client::Client;
+
+    const AUTHORIZE_URL: &str = "https://github.com/login/oauth/authorize?state=abc";
+
+    async fn link(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let res = if req.uri().path() == "/users/me/ex-accounts/link" {
+            Response::builder()
+                .status(StatusCode::FOUND)
+                .header(LOCATION, AUTHORIZE_URL)
+                .body(Body::empty())
+        } else if req.uri().path() == "/no-location/users/me/ex-accounts/link" {
+            Response::builder()
+                .status(StatusCode::FOUND)
+                .body(Body::empty())
+        } else {
+            Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(Body::empty())
+        };
+        Ok(res.unwrap())
+    }
+
+    #[tokio::test]
+    async fn link_returns_location() {
+        let client = serve(link);
+        let with_prefix = |prefix: &str| Client {
+            base_path: format!("{}{}", client.base_path, prefix),
+            ..client.clone()
+        };
+        let location = client
+            .request(LinkExternalAccount::new("github"))
+            .await
+            .unwrap();
+        assert_eq!(location, AUTHORIZE_URL);
+
+        let err = with_prefix("/missing")
+            .request(LinkExternalAccount::new("github"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::BadStatus(StatusCode::NOT_FOUND, _)));
+
+        let err = with_prefix("/no-location")
+            .request(LinkExternalAccount::new("github"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Custom(MissingLocation)));
+        assert!(LinkExternalAccount::new("github")
+            .parse(Bytes::new())
+            .is_err());
+    }
+}
@@ -0,0 +1,749 @@
+use std::convert::Infallible;
+use std::time::SystemTime;
+
+use hyper::body::Bytes;
+use hyper::{Body, Method};
+use itertools::Itertools;
+use uuid::Uuid;
+
+use super::{encode_query_value, format_timestamp, ApiError, ApiRequest, PaginatedRequest};
+use crate::models::{
+    EditMessageRequest, Message, MessagePin, MessageSearchResult, MessageStamps,
+    PostMessageStampRequest,
+};
+
+/// `GET /messages/{id}`
+#[derive(Debug, Clone)]
+pub struct GetMessage {
+    id: Uuid,
+}
+
+impl GetMessage {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetMessage {
+    type Response = Message;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/messages/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PUT /messages/{id}`
+#[derive(Debug, Clone)]
+pub struct EditMessage {
+    id: Uuid,
+    request: EditMessageRequest,
+}
+
+impl EditMessage {
+    pub fn new(id: Uuid, request: EditMessageRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for EditMessage {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/messages/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize EditMessageRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /messages/{id}`
+#[derive(Debug, Clone)]
+pub struct DeleteMessage {
+    id: Uuid,
+}
+
+impl DeleteMessage {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for DeleteMessage {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/messages/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /messages/{id}/stamps`
+#[derive(Debug, Clone)]
+pub struct GetMessageStamps {
+    id: Uuid,
+}
+
+impl GetMessageStamps {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetMessageStamps {
+    type Response = MessageStamps;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/messages/{}/stamps", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /messages/{message_id}/stamps/{stamp_id}`
+#[derive(Debug, Clone)]
+pub struct PostMessageStamp {
+    message_id: Uuid,
+    stamp_id: Uuid,
+    request: Option<PostMessageStampRequest>,
+}
+
+impl PostMessageStamp {
+    pub fn new(message_id: Uuid, stamp_id: Uuid) -> Self {
+        Self {
+            message_id,
+            stamp_id,
+            request: None,
+        }
+    }
+
+    pub fn count(self, count: i32) -> Self {
+        Self {
+            request: Some(PostMessageStampRequest { count }),
+            ..self
+        }
+    }
+}
+
+impl ApiRequest for PostMessageStamp {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/messages/{}/stamps/{}", self.message_id, self.stamp_id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn content_type(&self) -> Option<String> {
+        self.request
+            .as_ref()
+            .map(|_| "application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        if let Some(request) = &self.request {
+            serde_json::to_string(request)
+                .expect("failed to serialize PostMessageStampRequest")
+                .into()
+        } else {
+            Body::empty()
+        }
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /messages/{message_id}/stamps/{stamp_id}`
+#[derive(Debug, Clone)]
+pub struct DeleteMessageStamp {
+    message_id: Uuid,
+    stamp_id: Uuid,
+}
+
+impl DeleteMessageStamp {
+    pub fn new(message_id: Uuid, stamp_id: Uuid) -> Self {
+        Self {
+            message_id,
+            stamp_id,
+        }
+    }
+}
+
+impl ApiRequest for DeleteMessageStamp {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/messages/{}/stamps/{}", self.message_id, self.stamp_id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /messages/{id}/pin`
+#[derive(Debug, Clone)]
+pub struct GetMessagePin {
+    id: Uuid,
+}
+
+impl GetMessagePin {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+
+    /// `None` unless `message.pinned`, since traQ answers 404 for messages without a pin
+    pub fn of(message: &Message) -> Option<Self> {
+        message.pinned.then(|| Self::new(message.id))
+    }
+}
+
+impl ApiRequest for GetMessagePin {
+    type Response = MessagePin;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/messages/{}/pin", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /messages/{id}/pin`
+#[derive(Debug, Clone)]
+pub struct PostMessagePin {
+    id: Uuid,
+}
+
+impl PostMessagePin {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for PostMessagePin {
+    type Response = MessagePin;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/messages/{}/pin", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `DELETE /messages/{id}/pin`
+#[derive(Debug, Clone)]
+pub struct DeleteMessagePin {
+    id: Uuid,
+}
+
+impl DeleteMessagePin {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for DeleteMessagePin {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/messages/{}/pin", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// sort order of `SearchMessages`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageSearchSort {
+    CreatedAtAsc,
+    CreatedAtDesc,
+    UpdatedAtAsc,
+    UpdatedAtDesc,
+}
+
+impl MessageSearchSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CreatedAtAsc => "createdAt",
+            Self::CreatedAtDesc => "-createdAt",
+            Self::UpdatedAtAsc => "updatedAt",
+            Self::UpdatedAtDesc => "-updatedAt",
+        }
+    }
+}
+
+/// `GET /messages`
+#[derive(Debug, Clone, Default)]
+pub struct SearchMessages {
+    word: Option<String>,
+    after: Option<SystemTime>,
+    before: Option<SystemTime>,
+    in_channel: Option<Uuid>,
+    to: Vec<Uuid>,
+    from: Vec<Uuid>,
+    citation: Option<Uuid>,
+    bot: Option<bool>,
+    has_url: Option<bool>,
+    has_attachments: Option<bool>,
+    has_image: Option<bool>,
+    has_video: Option<bool>,
+    has_audio: Option<bool>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    sort: Option<MessageSearchSort>,
+}
+
+impl SearchMessages {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_word(self, word: &str) -> Self {
+        Self {
+            word: Some(word.to_string()),
+            ..self
+        }
+    }
+
+    pub fn set_after(self, after: SystemTime) -> Self {
+        Self {
+            after: Some(after),
+            ..self
+        }
+    }
+
+    pub fn set_before(self, before: SystemTime) -> Self {
+        Self {
+            before: Some(before),
+            ..self
+        }
+    }
+
+    pub fn set_in(self, channel_id: Uuid) -> Self {
+        Self {
+            in_channel: Some(channel_id),
+            ..self
+        }
+    }
+
+    pub fn add_to(mut self, user_id: Uuid) -> Self {
+        self.to.push(user_id);
+        self
+    }
+
+    pub fn add_from(mut self, user_id: Uuid) -> Self {
+        self.from.push(user_id);
+        self
+    }
+
+    pub fn set_citation(self, message_id: Uuid) -> Self {
+        Self {
+            citation: Some(message_id),
+            ..self
+        }
+    }
+
+    pub fn set_bot(self, bot: bool) -> Self {
+        Self {
+            bot: Some(bot),
+            ..self
+        }
+    }
+
+    pub fn set_has_url(self, has_url: bool) -> Self {
+        Self {
+            has_url: Some(has_url),
+            ..self
+        }
+    }
+
+    pub fn set_has_attachments(self, has_attachments: bool) -> Self {
+        Self {
+            has_attachments: Some(has_attachments),
+            ..self
+        }
+    }
+
+    pub fn set_has_image(self, has_image: bool) -> Self {
+        Self {
+            has_image: Some(has_image),
+            ..self
+        }
+    }
+
+    pub fn set_has_video(self, has_video: bool) -> Self {
+        Self {
+            has_video: Some(has_video),
+            ..self
+        }
+    }
+
+    pub fn set_has_audio(self, has_audio: bool) -> Self {
+        Self {
+            has_audio: Some(has_audio),
+            ..self
+        }
+    }
+
+    pub fn set_limit(self, limit: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    pub fn set_offset(self, offset: u32) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    pub fn set_sort(self, sort: MessageSearchSort) -> Self {
+        Self {
+            sort: Some(sort),
+            ..self
+        }
+    }
+}
+
+impl ApiRequest for SearchMessages {
+    type Response = MessageSearchResult;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        let mut query: Vec<(&str, String)> = vec![];
+        if let Some(word) = &self.word {
+            query.push(("word", encode_query_value(word)));
+        }
+        if let Some(after) = self.after {
+            query.push(("after", encode_query_value(&format_timestamp(after))));
+        }
+        if let Some(before) = self.before {
+            query.push(("before", encode_query_value(&format_timestamp(before))));
+        }
+        if let Some(in_channel) = self.in_channel {
+            query.push(("in", in_channel.to_string()));
+        }
+        for to in &self.to {
+            query.push(("to", to.to_string()));
+        }
+        for from in &self.from {
+            query.push(("from", from.to_string()));
+        }
+        if let Some(citation) = self.citation {
+            query.push(("citation", citation.to_string()));
+        }
+        if let Some(bot) = self.bot {
+            query.push(("bot", bot.to_string()));
+        }
+        if let Some(has_url) = self.has_url {
+            query.push(("hasURL", has_url.to_string()));
+        }
+        if let Some(has_attachments) = self.has_attachments {
+            query.push(("hasAttachments", has_attachments.to_string()));
+        }
+        if let Some(has_image) = self.has_image {
+            query.push(("hasImage", has_image.to_string()));
+        }
+        if let Some(has_video) = self.has_video {
+            query.push(("hasVideo", has_video.to_string()));
+        }
+        if let Some(has_audio) = self.has_audio {
+            query.push(("hasAudio", has_audio.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            query.push(("offset", offset.to_string()));
+        }
+        if let Some(sort) = self.sort {
+            query.push(("sort", sort.as_str().to_string()));
+        }
+        if query.is_empty() {
+            return "/messages".to_string();
+        }
+        let query = query
+            .into_iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .join("&");
+        format!("/messages?{}", query)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+impl PaginatedRequest for SearchMessages {
+    type Item = Message;
+
+    fn offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+
+    fn with_offset(&self, offset: u32) -> Self {
+        self.clone().set_offset(offset)
+    }
+
+    fn items(response: Self::Response) -> Vec<Self::Item> {
+        response.hits
+    }
+
+    fn total(response: &Self::Response) -> Option<u64> {
+        u64::try_from(response.total_hits).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use hyper::{Request, Response};
+
+    use super::*;
+    use crate::apis::serve;
+
+    #[test]
+    fn search_query() {
+        let a = Uuid::from_u128(1);
+        let b = Uuid::from_u128(2);
+        let c = Uuid::from_u128(3);
+        let req = SearchMessages::new()
+            .set_word("a b")
+            .set_after(UNIX_EPOCH + Duration::from_millis(1_704_067_200_123))
+            .add_to(a)
+            .add_to(b)
+            .add_from(c)
+            .set_has_url(true)
+            .set_limit(50);
+        assert_eq!(
+            req.uri(),
+            format!(
+                "/messages?word=a%20b&after=2024-01-01T00%3A00%3A00.123Z&to={}&to={}&from={}&hasURL=true&limit=50",
+                a, b, c
+            )
+        );
+    }
+
+    const TOTAL_HITS: u32 = 250;
+    const MAX_LIMIT: u32 = 100;
+
+    fn message(n: u32) -> Message {
+        Message {
+            id: Uuid::from_u128(n.into()),
+            user_id: Uuid::nil(),
+            channel_id: Uuid::nil(),
+            content: n.to_string(),
+            created_at: "2024-01-01T00:00:00.000Z".to_string(),
+            updated_at: "2024-01-01T00:00:00.000Z".to_string(),
+            pinned: false,
+            stamps: vec![],
+            thread_id: None,
+        }
+    }
+
+    /// serves `TOTAL_HITS` messages, capping `limit` at `MAX_LIMIT` as traQ does
+    async fn search(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let (mut limit, mut offset) = (20, 0);
+        for (k, v) in req
+            .uri()
+            .query()
+            .unwrap_or("")
+            .split('&')
+            .filter_map(|kv| kv.split_once('='))
+        {
+            match k {
+                "limit" => limit = v.parse().unwrap(),
+                "offset" => offset = v.parse().unwrap(),
+                _ => {}
+            }
+        }
+        let end = TOTAL_HITS.min(offset + u32::min(limit, MAX_LIMIT));
+        let result = MessageSearchResult {
+            total_hits: TOTAL_HITS.into(),
+            hits: (offset.min(end)..end).map(message).collect(),
+        };
+        Ok(Response::new(serde_json::to_vec(&result).unwrap().into()))
+    }
+
+    #[tokio::test]
+    async fn paginate_past_capped_limit() {
+        let client = serve(search);
+        let mut paginator = client.paginate(SearchMessages::new().set_limit(200));
+        let mut sizes = vec![];
+        while let Some(page) = paginator.next_page().await {
+            sizes.push(page.unwrap().len());
+        }
+        assert_eq!(sizes, [100, 100, 50]);
+
+        let all = client
+            .paginate(SearchMessages::new().set_limit(200))
+            .collect()
+            .await
+            .unwrap();
+        let expected: Vec<_> = (0..TOTAL_HITS).map(message).collect();
+        assert_eq!(all, expected);
+    }
+
+    #[test]
+    fn pin_request_only_for_pinned_messages() {
+        let message = |pinned: bool| -> Message {
+            serde_json::from_value(serde_json::json!({
+                "id": Uuid::from_u128(1),
+                "userId": Uuid::from_u128(2),
+                "channelId": Uuid::from_u128(3),
+                "content": "hello",
+                "createdAt": "2024-01-01T00:00:00Z",
+                "updatedAt": "2024-01-01T00:00:00Z",
+                "pinned": pinned,
+                "stamps": [],
+                "threadId": null,
+            }))
+            .unwrap()
+        };
+        assert!(GetMessagePin::of(&message(false)).is_none());
+        assert_eq!(
+            GetMessagePin::of(&message(true)).unwrap().uri(),
+            format!("/messages/{}/pin", Uuid::from_u128(1))
+        );
+    }
+}
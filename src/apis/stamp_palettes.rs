@@ -0,0 +1,204 @@
+use std::convert::Infallible;
+
+use hyper::body::Bytes;
+use hyper::{Body, Method};
+use uuid::Uuid;
+
+use super::{ApiError, ApiRequest};
+use crate::models::{
+    PatchStampPaletteRequest, PostStampPaletteRequest, StampPalette, StampPalettes,
+};
+
+/// `GET /stamp-palettes`
+#[derive(Debug, Clone, Default)]
+pub struct GetStampPalettes;
+
+impl GetStampPalettes {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl ApiRequest for GetStampPalettes {
+    type Response = StampPalettes;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/stamp-palettes".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /stamp-palettes/{id}`
+#[derive(Debug, Clone)]
+pub struct GetStampPalette {
+    id: Uuid,
+}
+
+impl GetStampPalette {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetStampPalette {
+    type Response = StampPalette;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/stamp-palettes/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /stamp-palettes`
+#[derive(Debug, Clone)]
+pub struct PostStampPalette {
+    request: PostStampPaletteRequest,
+}
+
+impl PostStampPalette {
+    pub fn new(request: PostStampPaletteRequest) -> Self {
+        Self { request }
+    }
+}
+
+impl ApiRequest for PostStampPalette {
+    type Response = StampPalette;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/stamp-palettes".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostStampPaletteRequest")
+            .into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PATCH /stamp-palettes/{id}`
+#[derive(Debug, Clone)]
+pub struct PatchStampPalette {
+    id: Uuid,
+    request: PatchStampPaletteRequest,
+}
+
+impl PatchStampPalette {
+    pub fn new(id: Uuid, request: PatchStampPaletteRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for PatchStampPalette {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/stamp-palettes/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchStampPaletteRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /stamp-palettes/{id}`
+#[derive(Debug, Clone)]
+pub struct DeleteStampPalette {
+    id: Uuid,
+}
+
+impl DeleteStampPalette {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for DeleteStampPalette {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/stamp-palettes/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
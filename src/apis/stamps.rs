@@ -0,0 +1,446 @@
+use std::convert::Infallible;
+
+use hyper::body::Bytes;
+use hyper::header::CONTENT_TYPE;
+use hyper::http::response::Parts as ResponseParts;
+use hyper::{Body, Method};
+use itertools::Itertools;
+use uuid::Uuid;
+
+use super::{ApiError, ApiRequest, Error};
+use crate::models::{PatchStampRequest, Stamp, StampImage, StampStats, Stamps};
+use crate::multipart::Multipart;
+
+/// `type` filter of `GetStamps`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StampType {
+    Unicode,
+    Original,
+}
+
+impl StampType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unicode => "unicode",
+            Self::Original => "original",
+        }
+    }
+}
+
+/// `GET /stamps`
+#[derive(Debug, Clone, Default)]
+pub struct GetStamps {
+    include_unicode: Option<bool>,
+    stamp_type: Option<StampType>,
+}
+
+impl GetStamps {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_include_unicode(self, include_unicode: bool) -> Self {
+        Self {
+            include_unicode: Some(include_unicode),
+            ..self
+        }
+    }
+
+    pub fn set_type(self, stamp_type: StampType) -> Self {
+        Self {
+            stamp_type: Some(stamp_type),
+            ..self
+        }
+    }
+}
+
+impl ApiRequest for GetStamps {
+    type Response = Stamps;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        let mut query: Vec<(&str, String)> = vec![];
+        if let Some(include_unicode) = self.include_unicode {
+            query.push(("include-unicode", include_unicode.to_string()));
+        }
+        if let Some(stamp_type) = self.stamp_type {
+            query.push(("type", stamp_type.as_str().to_string()));
+        }
+        if query.is_empty() {
+            return "/stamps".to_string();
+        }
+        let query = query
+            .into_iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .join("&");
+        format!("/stamps?{}", query)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /stamps/{id}`
+#[derive(Debug, Clone)]
+pub struct GetStamp {
+    id: Uuid,
+}
+
+impl GetStamp {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetStamp {
+    type Response = Stamp;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/stamps/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /stamps`
+#[derive(Debug, Clone)]
+pub struct PostStamp {
+    name: String,
+    mime: String,
+    data: Bytes,
+}
+
+impl PostStamp {
+    /// `data` is uploaded as is, so animated GIFs keep their frames
+    pub fn new(name: &str, mime: &str, data: Bytes) -> Self {
+        Self {
+            name: name.to_string(),
+            mime: mime.to_string(),
+            data,
+        }
+    }
+
+    fn form(&self) -> Multipart<'_> {
+        Multipart::new()
+            .text("name", &self.name)
+            .file("file", "stamp", &self.mime, &self.data)
+    }
+}
+
+impl ApiRequest for PostStamp {
+    type Response = Stamp;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/stamps".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some(self.form().content_type())
+    }
+
+    fn body(&self) -> Body {
+        self.form().finish().into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PATCH /stamps/{id}`
+#[derive(Debug, Clone)]
+pub struct PatchStamp {
+    id: Uuid,
+    request: PatchStampRequest,
+}
+
+impl PatchStamp {
+    pub fn new(id: Uuid, request: PatchStampRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for PatchStamp {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/stamps/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchStampRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /stamps/{id}`
+#[derive(Debug, Clone)]
+pub struct DeleteStamp {
+    id: Uuid,
+}
+
+impl DeleteStamp {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for DeleteStamp {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/stamps/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /stamps/{id}/image`
+#[derive(Debug, Clone)]
+pub struct GetStampImage {
+    id: Uuid,
+}
+
+impl GetStampImage {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetStampImage {
+    type Response = StampImage;
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/stamps/{}/image", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("image/png, image/jpeg, image/gif, image/svg+xml".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    /// without headers the mime type is unknown, see `parse_response`
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(StampImage {
+            mime: "application/octet-stream".to_string(),
+            data: body,
+        })
+    }
+
+    fn parse_response(
+        &self,
+        parts: &ResponseParts,
+        body: Bytes,
+    ) -> Result<Self::Response, Error<Self::Error>> {
+        if !parts.status.is_success() {
+            return Err(Error::BadStatus(parts.status, body));
+        }
+        let mime = parts
+            .headers
+            .get(CONTENT_TYPE)
+            .and_then(|m| m.to_str().ok());
+        let image = self.parse(body).map_err(Error::Custom)?;
+        Ok(match mime {
+            Some(mime) => StampImage {
+                mime: mime.to_string(),
+                ..image
+            },
+            None => image,
+        })
+    }
+}
+
+/// `PUT /stamps/{id}/image`
+#[derive(Debug, Clone)]
+pub struct PutStampImage {
+    id: Uuid,
+    mime: String,
+    data: Bytes,
+}
+
+impl PutStampImage {
+    pub fn new(id: Uuid, mime: &str, data: Bytes) -> Self {
+        Self {
+            id,
+            mime: mime.to_string(),
+            data,
+        }
+    }
+
+    fn form(&self) -> Multipart<'_> {
+        Multipart::new().file("file", "stamp", &self.mime, &self.data)
+    }
+}
+
+impl ApiRequest for PutStampImage {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/stamps/{}/image", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some(self.form().content_type())
+    }
+
+    fn body(&self) -> Body {
+        self.form().finish().into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /stamps/{id}/stats`
+#[derive(Debug, Clone)]
+pub struct GetStampStats {
+    id: Uuid,
+}
+
+impl GetStampStats {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetStampStats {
+    type Response = StampStats;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/stamps/{}/stats", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::{Request, Response};
+
+    use super::*;
+    use crate::apis::serve;
+
+    const SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg"/>"#;
+
+    async fn image(_req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let res = Response::builder()
+            .header(CONTENT_TYPE, "image/svg+xml")
+            .body(SVG.into());
+        Ok(res.unwrap())
+    }
+
+    #[tokio::test]
+    async fn stamp_image_keeps_raw_svg() {
+        let client = serve(image);
+        let image = client
+            .request(GetStampImage::new(Uuid::nil()))
+            .await
+            .unwrap();
+        assert_eq!(
+            image,
+            StampImage {
+                mime: "image/svg+xml".to_string(),
+                data: Bytes::from_static(SVG.as_bytes()),
+            }
+        );
+    }
+}
@@ -7,11 +7,11 @@ use image::ImageFormat;
 use itertools::Itertools;
 use uuid::Uuid;
 
-use super::{ApiError, ApiRequest};
+use super::{encode_query_value, ApiError, ApiRequest, PaginatedRequest};
 use crate::models::{
     DirectMessageChannel, Image, Message, Messages, PatchUserRequest, PatchUserTagRequest,
     PostMessageRequest, PostUserRequest, PostUserTagRequest, PutUserPasswordRequest, User,
-    UserDetail, UserStats, UserTag, UserTags, Users,
+    UserAccountState, UserDetail, UserStats, UserTag, UserTags, Users,
 };
 
 /// `GET /users`
@@ -19,6 +19,9 @@ use crate::models::{
 pub struct GetUsers {
     pub include_suspended: bool,
     pub name: Option<String>,
+    /// filtered on the client side; traQ has no query parameter for it.
+    /// non-active states imply `include-suspended=true`
+    pub state: Option<UserAccountState>,
 }
 
 impl GetUsers {
@@ -26,6 +29,14 @@ impl GetUsers {
         Self {
             include_suspended,
             name,
+            state: None,
+        }
+    }
+
+    pub fn state(self, state: UserAccountState) -> Self {
+        Self {
+            state: Some(state),
+            ..self
         }
     }
 }
@@ -35,7 +46,9 @@ impl ApiRequest for GetUsers {
     type Error = ApiError;
 
     fn uri(&self) -> String {
-        let uri = format!("/users?include-suspended={}", self.include_suspended);
+        let include_suspended =
+            self.include_suspended || self.state.map_or(false, |s| !s.is_active());
+        let uri = format!("/users?include-suspended={}", include_suspended);
         if let Some(name) = &self.name {
             format!("{}&name={}", uri, name)
         } else {
@@ -57,7 +70,10 @@ impl ApiRequest for GetUsers {
 
     fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
         let s = std::str::from_utf8(&body)?;
-        let r = serde_json::from_str(s)?;
+        let mut r: Users = serde_json::from_str(s)?;
+        if let Some(state) = self.state {
+            r.retain(|u| u.state == state);
+        }
         Ok(r)
     }
 }
@@ -308,10 +324,10 @@ impl ApiRequest for GetDirectMessages {
             query.push(("offset", offset.to_string()));
         }
         if let Some(since) = &self.since {
-            query.push(("since", since.clone()));
+            query.push(("since", encode_query_value(since)));
         }
         if let Some(until) = &self.until {
-            query.push(("until", until.clone()))
+            query.push(("until", encode_query_value(until)));
         }
         if let Some(inclusive) = self.inclusive {
             query.push(("inclusive", inclusive.to_string()));
@@ -349,6 +365,22 @@ impl ApiRequest for GetDirectMessages {
     }
 }
 
+impl PaginatedRequest for GetDirectMessages {
+    type Item = Message;
+
+    fn offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+
+    fn with_offset(&self, offset: u32) -> Self {
+        self.clone().set_offset(offset)
+    }
+
+    fn items(response: Self::Response) -> Vec<Self::Item> {
+        response
+    }
+}
+
 /// `GET /users/{id}/stats`
 #[derive(Debug, Clone)]
 pub struct GetUserStats {
@@ -722,3 +754,77 @@ impl ApiRequest for GetDirectMessageChannel {
         Ok(r)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use hyper::http::StatusCode;
+    use hyper::{Request, Response};
+
+    use super::*;
+    use crate::apis::serve;
+
+    fn user(id: u128, state: u8) -> serde_json::Value {
+        serde_json::json!({
+            "id": Uuid::from_u128(id),
+            "name": format!("user{}", id),
+            "displayName": "User",
+            "iconFileId": Uuid::nil(),
+            "bot": false,
+            "state": state,
+            "updatedAt": "2024-01-01T00:00:00Z",
+        })
+    }
+
+    /// answers only when suspended users were asked for
+    async fn users(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let res = if req.uri().query() == Some("include-suspended=true") {
+            let users = serde_json::json!([user(1, 0), user(2, 1), user(3, 2), user(4, 2)]);
+            Response::new(Body::from(users.to_string()))
+        } else {
+            Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .body(Body::empty())
+                .unwrap()
+        };
+        Ok(res)
+    }
+
+    #[tokio::test]
+    async fn filters_by_state() {
+        let client = serve(users);
+        let suspended = client
+            .request(GetUsers::new(false, None).state(UserAccountState::Suspended))
+            .await
+            .unwrap();
+        let ids: Vec<_> = suspended.iter().map(|u| u.id).collect();
+        assert_eq!(ids, [Uuid::from_u128(3), Uuid::from_u128(4)]);
+        assert!(suspended.iter().all(User::is_suspended));
+
+        let active = client
+            .request(GetUsers::new(true, None).state(UserAccountState::Active))
+            .await
+            .unwrap();
+        let ids: Vec<_> = active.iter().map(|u| u.id).collect();
+        assert_eq!(ids, [Uuid::from_u128(2)]);
+    }
+
+    #[test]
+    fn non_active_state_includes_suspended() {
+        assert_eq!(
+            GetUsers::new(false, None).uri(),
+            "/users?include-suspended=false"
+        );
+        assert_eq!(
+            GetUsers::new(false, None)
+                .state(UserAccountState::Active)
+                .uri(),
+            "/users?include-suspended=false"
+        );
+        assert_eq!(
+            GetUsers::new(false, None)
+                .state(UserAccountState::Deactivated)
+                .uri(),
+            "/users?include-suspended=true"
+        );
+    }
+}
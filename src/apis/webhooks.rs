@@ -0,0 +1,593 @@
+use std::convert::Infallible;
+use std::io::Cursor;
+
+use hmac::{Hmac, Mac};
+use hyper::body::Bytes;
+use hyper::{Body, Method};
+use itertools::Itertools;
+use sha1::Sha1;
+use uuid::Uuid;
+
+use super::{encode_query_value, ApiError, ApiRequest, PaginatedRequest};
+use crate::models::{
+    Image, Message, Messages, PatchWebhookRequest, PostWebhookRequest, Webhook, Webhooks,
+};
+use crate::multipart::Multipart;
+
+/// `GET /webhooks`
+#[derive(Debug, Clone, Default)]
+pub struct GetWebhooks {
+    all: bool,
+}
+
+impl GetWebhooks {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// includes webhooks owned by other users; requires `AccessOthersWebhook`
+    pub fn all(self, all: bool) -> Self {
+        Self { all }
+    }
+}
+
+impl ApiRequest for GetWebhooks {
+    type Response = Webhooks;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        if self.all {
+            return "/webhooks?all=true".to_string();
+        }
+        "/webhooks".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `GET /webhooks/{id}`
+#[derive(Debug, Clone)]
+pub struct GetWebhook {
+    id: Uuid,
+}
+
+impl GetWebhook {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetWebhook {
+    type Response = Webhook;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/webhooks/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `POST /webhooks`
+#[derive(Debug, Clone)]
+pub struct PostWebhook {
+    request: PostWebhookRequest,
+}
+
+impl PostWebhook {
+    pub fn new(request: PostWebhookRequest) -> Self {
+        Self { request }
+    }
+}
+
+impl ApiRequest for PostWebhook {
+    type Response = Webhook;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        "/webhooks".to_string()
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PostWebhookRequest")
+            .into()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+/// `PATCH /webhooks/{id}`
+#[derive(Debug, Clone)]
+pub struct PatchWebhook {
+    id: Uuid,
+    request: PatchWebhookRequest,
+}
+
+impl PatchWebhook {
+    pub fn new(id: Uuid, request: PatchWebhookRequest) -> Self {
+        Self { id, request }
+    }
+}
+
+impl ApiRequest for PatchWebhook {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/webhooks/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        serde_json::to_string(&self.request)
+            .expect("failed to serialize PatchWebhookRequest")
+            .into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `DELETE /webhooks/{id}`
+#[derive(Debug, Clone)]
+pub struct DeleteWebhook {
+    id: Uuid,
+}
+
+impl DeleteWebhook {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for DeleteWebhook {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/webhooks/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /webhooks/{id}/icon`
+#[derive(Debug, Clone)]
+pub struct GetWebhookIcon {
+    id: Uuid,
+}
+
+impl GetWebhookIcon {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+impl ApiRequest for GetWebhookIcon {
+    type Response = Image;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        format!("/webhooks/{}/icon", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("image/jpeg, image/png, image/gif".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        use image::io::Reader as ImageReader;
+
+        let cursor = Cursor::new(body);
+        let img = ImageReader::new(cursor).with_guessed_format()?.decode()?;
+        Ok(img)
+    }
+}
+
+/// `PUT /webhooks/{id}/icon`
+#[derive(Debug, Clone)]
+pub struct PutWebhookIcon {
+    id: Uuid,
+    mime: String,
+    data: Bytes,
+}
+
+impl PutWebhookIcon {
+    pub fn new(id: Uuid, mime: &str, data: Bytes) -> Self {
+        Self {
+            id,
+            mime: mime.to_string(),
+            data,
+        }
+    }
+
+    fn form(&self) -> Multipart<'_> {
+        Multipart::new().file("file", "icon", &self.mime, &self.data)
+    }
+}
+
+impl ApiRequest for PutWebhookIcon {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        format!("/webhooks/{}/icon", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some(self.form().content_type())
+    }
+
+    fn body(&self) -> Body {
+        self.form().finish().into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+/// `GET /webhooks/{id}/messages`
+#[derive(Debug, Clone)]
+pub struct GetWebhookMessages {
+    id: Uuid,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    since: Option<String>,
+    until: Option<String>,
+    inclusive: Option<bool>,
+    order: Option<String>,
+}
+
+impl GetWebhookMessages {
+    pub fn new(id: Uuid) -> Self {
+        Self {
+            id,
+            limit: None,
+            offset: None,
+            since: None,
+            until: None,
+            inclusive: None,
+            order: None,
+        }
+    }
+
+    pub fn set_limit(self, limit: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    pub fn set_offset(self, offset: u32) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    pub fn set_since(self, since: &str) -> Self {
+        Self {
+            since: Some(since.to_string()),
+            ..self
+        }
+    }
+
+    pub fn set_until(self, until: &str) -> Self {
+        Self {
+            until: Some(until.to_string()),
+            ..self
+        }
+    }
+
+    pub fn set_inclusive(self, inclusive: bool) -> Self {
+        Self {
+            inclusive: Some(inclusive),
+            ..self
+        }
+    }
+
+    pub fn set_order(self, order: &str) -> Self {
+        Self {
+            order: Some(order.to_string()),
+            ..self
+        }
+    }
+}
+
+impl ApiRequest for GetWebhookMessages {
+    type Response = Messages;
+    type Error = ApiError;
+
+    fn uri(&self) -> String {
+        let mut query: Vec<(&str, String)> = vec![];
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            query.push(("offset", offset.to_string()));
+        }
+        if let Some(since) = &self.since {
+            query.push(("since", encode_query_value(since)));
+        }
+        if let Some(until) = &self.until {
+            query.push(("until", encode_query_value(until)));
+        }
+        if let Some(inclusive) = self.inclusive {
+            query.push(("inclusive", inclusive.to_string()));
+        }
+        if let Some(order) = &self.order {
+            query.push(("order", order.clone()));
+        }
+        let s = format!("/webhooks/{}/messages", self.id);
+        if query.is_empty() {
+            return s;
+        }
+        let query = query
+            .into_iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .join("&");
+        format!("{}?{}", s, query)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn accept(&self) -> Option<String> {
+        Some("application/json".to_string())
+    }
+
+    fn body(&self) -> Body {
+        Body::empty()
+    }
+
+    fn parse(&self, body: Bytes) -> Result<Self::Response, Self::Error> {
+        let s = std::str::from_utf8(&body)?;
+        let r = serde_json::from_str(s)?;
+        Ok(r)
+    }
+}
+
+impl PaginatedRequest for GetWebhookMessages {
+    type Item = Message;
+
+    fn offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+
+    fn with_offset(&self, offset: u32) -> Self {
+        self.clone().set_offset(offset)
+    }
+
+    fn items(response: Self::Response) -> Vec<Self::Item> {
+        response
+    }
+}
+
+/// `POST /webhooks/{id}`
+#[derive(Debug, Clone)]
+pub struct PostWebhookMessage {
+    id: Uuid,
+    content: String,
+    secret: Option<String>,
+    channel_id: Option<Uuid>,
+    embed: bool,
+}
+
+impl PostWebhookMessage {
+    pub fn new(id: Uuid, content: &str) -> Self {
+        Self {
+            id,
+            content: content.to_string(),
+            secret: None,
+            channel_id: None,
+            embed: false,
+        }
+    }
+
+    /// signs the message with `X-TRAQ-Signature`; required for secure webhooks
+    pub fn secret(self, secret: &str) -> Self {
+        Self {
+            secret: Some(secret.to_string()),
+            ..self
+        }
+    }
+
+    /// posts to `channel_id` instead of the webhook's default channel
+    pub fn channel_id(self, channel_id: Uuid) -> Self {
+        Self {
+            channel_id: Some(channel_id),
+            ..self
+        }
+    }
+
+    pub fn embed(self, embed: bool) -> Self {
+        Self { embed, ..self }
+    }
+
+    /// hex-encoded HMAC-SHA1 of the content keyed by the webhook secret
+    pub fn signature(&self) -> Option<String> {
+        let secret = self.secret.as_ref()?;
+        let mut mac = Hmac::<Sha1>::new_from_slice(secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(self.content.as_bytes());
+        let signature = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .join("");
+        Some(signature)
+    }
+}
+
+impl ApiRequest for PostWebhookMessage {
+    type Response = ();
+    type Error = Infallible;
+
+    fn uri(&self) -> String {
+        if self.embed {
+            return format!("/webhooks/{}?embed=1", self.id);
+        }
+        format!("/webhooks/{}", self.id)
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn content_type(&self) -> Option<String> {
+        Some("text/plain; charset=utf-8".to_string())
+    }
+
+    fn headers(&self) -> Vec<(String, String)> {
+        let mut headers = vec![];
+        if let Some(signature) = self.signature() {
+            headers.push(("X-TRAQ-Signature".to_string(), signature));
+        }
+        if let Some(channel_id) = self.channel_id {
+            headers.push(("X-TRAQ-Channel-Id".to_string(), channel_id.to_string()));
+        }
+        headers
+    }
+
+    fn body(&self) -> Body {
+        self.content.clone().into()
+    }
+
+    fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apis::Error;
+    use crate::client::Client;
+
+    const CONTENT: &str = "The quick brown fox jumps over the lazy dog";
+
+    #[test]
+    fn signature_known_vector() {
+        let req = PostWebhookMessage::new(Uuid::nil(), CONTENT).secret("key");
+        assert_eq!(
+            req.signature().as_deref(),
+            Some("de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9")
+        );
+        assert_eq!(
+            PostWebhookMessage::new(Uuid::nil(), CONTENT).signature(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn sends_signature_channel_and_embed() {
+        let client = Client::builder()
+            .base_path("http://traq.test/api/v3")
+            .dry_run(true)
+            .build();
+        let id = Uuid::from_u128(1);
+        let channel_id = Uuid::from_u128(2);
+        let req = PostWebhookMessage::new(id, CONTENT)
+            .secret("key")
+            .channel_id(channel_id)
+            .embed(true);
+        assert!(matches!(client.request(req).await, Err(Error::DryRun)));
+
+        let recorded = client.recorded_requests().remove(0);
+        assert_eq!(
+            recorded.uri.to_string(),
+            format!("http://traq.test/api/v3/webhooks/{}?embed=1", id)
+        );
+        assert_eq!(
+            recorded.headers["x-traq-signature"],
+            "de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9"
+        );
+        assert_eq!(
+            recorded.headers["x-traq-channel-id"],
+            channel_id.to_string().as_str()
+        );
+        assert_eq!(&recorded.body[..], CONTENT.as_bytes());
+    }
+}
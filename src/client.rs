@@ -1,9 +1,11 @@
 use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
 
+use hyper::body::Bytes;
 use hyper::client::HttpConnector;
 use hyper::service::Service;
 use hyper::Client as HyperClient;
-use hyper::{Body, Request, Response};
+use hyper::{Body, HeaderMap, Method, Request, Response, Uri};
 use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
 
 use crate::auth::Authorization;
@@ -13,6 +15,60 @@ pub struct Client {
     pub base_path: String,
     pub authorization: Authorization,
     pub inner: HyperClient<HttpsConnector<HttpConnector>, Body>,
+    /// when set, `Client::request` records built requests here instead of sending them;
+    /// raw requests sent through `Service<Request<Body>>` are not intercepted
+    pub(crate) recorder: Option<Recorder>,
+}
+
+/// A request built by `Client::build_request`, with its body read into memory
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub uri: Uri,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn record(&self, request: RecordedRequest) {
+        self.requests
+            .lock()
+            .expect("recorder lock poisoned")
+            .push(request);
+    }
+
+    /// reads the body of `request` into memory and records it
+    pub async fn record_request(&self, request: Request<Body>) -> Result<(), hyper::Error> {
+        let (parts, body) = request.into_parts();
+        let body = hyper::body::to_bytes(body).await?;
+        self.record(RecordedRequest {
+            method: parts.method,
+            uri: parts.uri,
+            headers: parts.headers,
+            body,
+        });
+        Ok(())
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests
+            .lock()
+            .expect("recorder lock poisoned")
+            .clone()
+    }
+
+    pub fn take(&self) -> Vec<RecordedRequest> {
+        std::mem::take(&mut *self.requests.lock().expect("recorder lock poisoned"))
+    }
 }
 
 impl Default for Client {
@@ -33,6 +89,7 @@ impl Default for Client {
             base_path: "https://q.trap.jp/api/v3".to_string(),
             authorization: Authorization::None,
             inner,
+            recorder: None,
         }
     }
 }
@@ -41,12 +98,25 @@ impl Default for Client {
 pub struct Builder {
     pub base_path: Option<String>,
     pub authorization: Authorization,
+    pub dry_run: bool,
 }
 
 impl Client {
     pub fn builder() -> Builder {
         Default::default()
     }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// requests recorded so far in dry-run mode; always empty otherwise
+    pub fn recorded_requests(&self) -> Vec<RecordedRequest> {
+        self.recorder
+            .as_ref()
+            .map(Recorder::requests)
+            .unwrap_or_default()
+    }
 }
 
 impl Builder {
@@ -76,13 +146,29 @@ impl Builder {
         }
     }
 
+    /// makes `Client::request` record requests instead of sending them, see `recorded_requests`;
+    /// raw `Request<Body>`s sent through the `Service` impl are still sent
+    pub fn dry_run(self, dry_run: bool) -> Self {
+        Self { dry_run, ..self }
+    }
+
     pub fn build(self) -> Client {
         let Builder {
             base_path,
             authorization,
+            dry_run,
         } = self;
         let client: Client = Default::default();
 
+        let client = if dry_run {
+            Client {
+                recorder: Some(Recorder::new()),
+                ..client
+            }
+        } else {
+            client
+        };
+
         let client = if let Some(base_path) = base_path {
             Client {
                 base_path,
@@ -103,6 +189,7 @@ impl Builder {
     }
 }
 
+/// Sends `req` as is, even in dry-run mode, which only covers `Client::request`
 impl Service<Request<Body>> for Client {
     type Response = Response<Body>;
     type Error = hyper::Error;
@@ -126,3 +213,119 @@ impl Service<Request<Body>> for Client {
         self.inner.call(req)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use hyper::header::{AUTHORIZATION, CONTENT_TYPE};
+
+    use super::*;
+    use crate::apis::{ApiRequest, Error};
+
+    /// stands in for the generated requests so these tests only exercise the recorder
+    #[derive(Debug, Clone)]
+    struct Echo {
+        method: Method,
+        uri: &'static str,
+        content_type: Option<&'static str>,
+        body: &'static str,
+    }
+
+    impl ApiRequest for Echo {
+        type Response = ();
+        type Error = Infallible;
+
+        fn uri(&self) -> String {
+            self.uri.to_string()
+        }
+
+        fn method(&self) -> Method {
+            self.method.clone()
+        }
+
+        fn content_type(&self) -> Option<String> {
+            self.content_type.map(str::to_string)
+        }
+
+        fn body(&self) -> Body {
+            self.body.into()
+        }
+
+        fn parse(&self, _body: Bytes) -> Result<Self::Response, Self::Error> {
+            Ok(())
+        }
+    }
+
+    fn dry_run_client() -> Client {
+        Client::builder()
+            .base_path("http://traq.test/api/v3")
+            .authorization_bearer("token")
+            .dry_run(true)
+            .build()
+    }
+
+    fn single(client: &Client) -> RecordedRequest {
+        let mut requests = client.recorded_requests();
+        assert_eq!(requests.len(), 1);
+        requests.remove(0)
+    }
+
+    #[tokio::test]
+    async fn records_get_with_query() {
+        let client = dry_run_client();
+        let req = Echo {
+            method: Method::GET,
+            uri: "/channels?include-dm=true&path=a%2Fb",
+            content_type: None,
+            body: "",
+        };
+        assert!(matches!(client.request(req).await, Err(Error::DryRun)));
+
+        let recorded = single(&client);
+        assert_eq!(recorded.method, Method::GET);
+        assert_eq!(
+            recorded.uri.to_string(),
+            "http://traq.test/api/v3/channels?include-dm=true&path=a%2Fb"
+        );
+        assert_eq!(recorded.headers[AUTHORIZATION], "Bearer token");
+        assert!(!recorded.headers.contains_key(CONTENT_TYPE));
+        assert!(recorded.body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn records_json_post() {
+        let client = dry_run_client();
+        let req = Echo {
+            method: Method::POST,
+            uri: "/messages",
+            content_type: Some("application/json"),
+            body: r#"{"content":"hello","embed":true}"#,
+        };
+        assert!(matches!(client.request(req).await, Err(Error::DryRun)));
+
+        let recorded = single(&client);
+        assert_eq!(recorded.method, Method::POST);
+        assert_eq!(recorded.uri.path(), "/api/v3/messages");
+        assert_eq!(recorded.headers[CONTENT_TYPE], "application/json");
+        assert_eq!(&recorded.body[..], br#"{"content":"hello","embed":true}"#);
+    }
+
+    #[tokio::test]
+    async fn records_raw_requests() {
+        let recorder = Recorder::new();
+        let req = Request::post("http://traq.test/api/v3/raw")
+            .header(CONTENT_TYPE, "text/plain")
+            .body(Body::from("raw"))
+            .unwrap();
+        recorder.record_request(req).await.unwrap();
+
+        let recorded = recorder.take();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].method, Method::POST);
+        assert_eq!(recorded[0].uri.path(), "/api/v3/raw");
+        assert_eq!(recorded[0].headers[CONTENT_TYPE], "text/plain");
+        assert_eq!(&recorded[0].body[..], b"raw");
+        assert!(recorder.requests().is_empty());
+    }
+}
@@ -0,0 +1,197 @@
+use hyper::header::AUTHORIZATION;
+
+use crate::apis::{ApiRequest, Error};
+use crate::client::Client;
+
+impl Client {
+    /// Renders `req` as an equivalent `curl` command line.
+    /// With `mask_token`, the bearer token is replaced by `***`.
+    pub async fn curl_command<Req>(
+        &self,
+        req: &Req,
+        mask_token: bool,
+    ) -> Result<String, Error<Req::Error>>
+    where
+        Req: ApiRequest,
+    {
+        let raw_req = self.build_request(req).map_err(Error::HyperHttp)?;
+        let (parts, body) = raw_req.into_parts();
+        let body = hyper::body::to_bytes(body).await.map_err(Error::Hyper)?;
+        let mut args = vec![
+            "curl".to_string(),
+            "-X".to_string(),
+            parts.method.to_string(),
+            quote(parts.uri.to_string().as_bytes()),
+        ];
+        for (name, value) in &parts.headers {
+            let value = if mask_token && name == AUTHORIZATION {
+                "Bearer ***".as_bytes()
+            } else {
+                value.as_bytes()
+            };
+            let mut header = format!("{}: ", name).into_bytes();
+            header.extend_from_slice(value);
+            args.push("-H".to_string());
+            args.push(quote(&header));
+        }
+        if body.is_empty() {
+            return Ok(args.join(" "));
+        }
+        args.push("--data-binary".to_string());
+        if is_plain_text(&body) {
+            args.push(quote(&body));
+            Ok(args.join(" "))
+        } else {
+            // NUL and CR can't survive shell quoting, so pipe the body in through printf
+            args.push("@-".to_string());
+            Ok(format!(
+                "printf {} | {}",
+                printf_format(&body),
+                args.join(" ")
+            ))
+        }
+    }
+}
+
+/// UTF-8 without control characters other than `\n` and `\t`
+fn is_plain_text(s: &[u8]) -> bool {
+    std::str::from_utf8(s).map_or(false, |s| {
+        s.chars().all(|c| c == '\n' || c == '\t' || !c.is_control())
+    })
+}
+
+/// Single-quoted `printf` format that reproduces `s` byte for byte.
+/// A leading `-` is escaped too, as printf would otherwise read it as an option
+fn printf_format(s: &[u8]) -> String {
+    let escaped: String = s
+        .iter()
+        .enumerate()
+        .map(|(i, b)| match b {
+            b'%' => "%%".to_string(),
+            b'-' if i == 0 => format!("\\{:03o}", b),
+            b'\'' | b'\\' => format!("\\{:03o}", b),
+            0x20..=0x7e => (*b as char).to_string(),
+            _ => format!("\\{:03o}", b),
+        })
+        .collect();
+    format!("'{}'", escaped)
+}
+
+/// Quotes `s` for POSIX shells, falling back to bash's `$'...'` for non UTF-8 input
+fn quote(s: &[u8]) -> String {
+    match std::str::from_utf8(s) {
+        Ok(s) => format!("'{}'", s.replace('\'', r"'\''")),
+        Err(_) => {
+            let escaped: String = s
+                .iter()
+                .map(|b| match b {
+                    b'\'' => r"\'".to_string(),
+                    b'\\' => r"\\".to_string(),
+                    0x20..=0x7e => (*b as char).to_string(),
+                    _ => format!("\\x{:02x}", b),
+                })
+                .collect();
+            format!("$'{}'", escaped)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::body::Bytes;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::apis::channels::PostChannelMessage;
+    use crate::apis::files::PostFile;
+    use crate::apis::me::GetMe;
+    use crate::models::PostMessageRequest;
+
+    fn client() -> Client {
+        Client::builder()
+            .base_path("http://traq.test/api/v3")
+            .authorization_bearer("secret-token")
+            .build()
+    }
+
+    #[test]
+    fn quote_escapes_single_quotes() {
+        assert_eq!(quote(b"it's"), r"'it'\''s'");
+    }
+
+    #[tokio::test]
+    async fn masks_token() {
+        let client = client();
+        let masked = client.curl_command(&GetMe, true).await.unwrap();
+        assert!(masked.contains("'authorization: Bearer ***'"));
+        assert!(!masked.contains("secret-token"));
+        let unmasked = client.curl_command(&GetMe, false).await.unwrap();
+        assert!(unmasked.contains("'authorization: Bearer secret-token'"));
+    }
+
+    #[tokio::test]
+    async fn quotes_text_body() {
+        let req = PostChannelMessage::new(
+            Uuid::nil(),
+            PostMessageRequest {
+                content: "it's".to_string(),
+                embed: false,
+            },
+        );
+        let command = client().curl_command(&req, true).await.unwrap();
+        assert!(command.starts_with("curl -X POST "));
+        assert!(command.ends_with(r#"--data-binary '{"content":"it'\''s","embed":false}'"#));
+    }
+
+    #[tokio::test]
+    async fn pipes_multipart_body() {
+        let data = Bytes::from_static(b"\x00'%\\");
+        let req = PostFile::new(Uuid::nil(), "a.bin", "application/octet-stream", data);
+        let command = client().curl_command(&req, true).await.unwrap();
+        assert!(command.starts_with(r"printf '\055-"));
+        assert!(command.contains(r"\015\012"));
+        assert!(command.contains(r"\000\047%%\134"));
+        assert!(command.ends_with("--data-binary @-"));
+    }
+
+    /// runs `command` through `sh` with `curl` replaced by a function printing its stdin
+    #[cfg(unix)]
+    fn piped_body(command: &str) -> Vec<u8> {
+        let output = std::process::Command::new("sh")
+            .arg("-c")
+            .arg(format!("curl() {{ cat; }}; {}", command))
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{:?}", output);
+        output.stdout
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn printf_format_round_trips() {
+        let mut body: Vec<u8> = b"--".to_vec();
+        body.extend(0..=255);
+        assert_eq!(
+            piped_body(&format!("printf {}", printf_format(&body))),
+            body
+        );
+        let body: Vec<u8> = (0..=255).collect();
+        assert_eq!(
+            piped_body(&format!("printf {}", printf_format(&body))),
+            body
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn multipart_command_runs() {
+        let client = client();
+        let data = Bytes::from_static(b"\x00'%\\");
+        let req = PostFile::new(Uuid::nil(), "a.bin", "application/octet-stream", data);
+        let command = client.curl_command(&req, true).await.unwrap();
+        let body = client.build_request(&req).unwrap().into_body();
+        let body = hyper::body::to_bytes(body).await.unwrap();
+        assert!(body.starts_with(b"--"));
+        assert_eq!(piped_body(&command), body);
+    }
+}
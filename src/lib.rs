@@ -1,7 +1,9 @@
 pub mod apis;
 pub mod auth;
 pub mod client;
+mod curl;
 pub mod models;
+mod multipart;
 
 pub use auth::Authorization;
 pub use client::{Builder as ClientBuilder, Client};
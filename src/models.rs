@@ -1,5 +1,6 @@
+use hyper::body::Bytes;
 use image::DynamicImage;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use uuid::Uuid;
 
@@ -12,14 +13,24 @@ pub struct User {
     pub display_name: String,
     pub icon_file_id: Uuid,
     pub bot: bool,
-    pub state: i32,
+    pub state: UserAccountState,
     pub updated_at: String,
 }
 
+impl User {
+    pub fn is_active(&self) -> bool {
+        self.state.is_active()
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.state.is_suspended()
+    }
+}
+
 pub type Users = Vec<User>;
 
 /// https://github.com/traPtitech/traQ/blob/bf768fc1d4ce1d5eb1575dd64f008f70f97087dd/model/users.go#L41-L48
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize_repr, Serialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize_repr, Serialize_repr)]
 #[repr(u8)]
 pub enum UserAccountState {
     Deactivated = 0,
@@ -27,6 +38,16 @@ pub enum UserAccountState {
     Suspended = 2,
 }
 
+impl UserAccountState {
+    pub fn is_active(&self) -> bool {
+        matches!(self, Self::Active)
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        matches!(self, Self::Suspended)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserTag {
@@ -58,6 +79,16 @@ pub struct UserDetail {
     pub home_channel: Option<Uuid>,
 }
 
+impl UserDetail {
+    pub fn is_active(&self) -> bool {
+        self.state.is_active()
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.state.is_suspended()
+    }
+}
+
 /// https://github.com/traPtitech/traQ/blob/bf768fc1d4ce1d5eb1575dd64f008f70f97087dd/router/v3/users.go#L347-L352
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -80,6 +111,12 @@ pub struct MessageStamp {
 
 pub type MessageStamps = Vec<MessageStamp>;
 
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostMessageStampRequest {
+    pub count: i32,
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Message {
@@ -89,6 +126,7 @@ pub struct Message {
     pub content: String,
     pub created_at: String,
     pub updated_at: String,
+    /// `GetMessagePin::of` fetches who pinned it and when
     pub pinned: bool,
     pub stamps: MessageStamps,
     pub thread_id: Option<Uuid>,
@@ -96,6 +134,93 @@ pub struct Message {
 
 pub type Messages = Vec<Message>;
 
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageSearchResult {
+    pub total_hits: i64,
+    pub hits: Messages,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipFolder {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: String,
+    pub owner_id: Uuid,
+    pub description: String,
+}
+
+pub type ClipFolders = Vec<ClipFolder>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClippedMessage {
+    pub message: Message,
+    pub clipped_at: String,
+}
+
+pub type ClippedMessages = Vec<ClippedMessage>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageClip {
+    pub folder_id: Uuid,
+    pub clipped_at: String,
+}
+
+pub type MessageClips = Vec<MessageClip>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostClipFolderRequest {
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchClipFolderRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostClipFolderMessageRequest {
+    pub message_id: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessagePin {
+    pub user_id: Uuid,
+    pub pinned_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Pin {
+    pub user_id: Uuid,
+    pub pinned_at: String,
+    pub message: Message,
+}
+
+pub type Pins = Vec<Pin>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityTimelineMessage {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub channel_id: Uuid,
+    pub content: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+pub type ActivityTimelineMessages = Vec<ActivityTimelineMessage>;
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PostMessageRequest {
@@ -103,6 +228,12 @@ pub struct PostMessageRequest {
     pub embed: bool,
 }
 
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditMessageRequest {
+    pub content: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserStatsStamp {
@@ -121,12 +252,282 @@ pub struct UserStats {
 
 pub type Image = DynamicImage;
 
+/// A stamp image as served: PNG, JPEG, GIF (possibly animated) or SVG for unicode stamps
+#[derive(Debug, Clone, PartialEq)]
+pub struct StampImage {
+    /// the response's `Content-Type`
+    pub mime: String,
+    pub data: Bytes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum BotMode {
+    #[serde(rename = "HTTP")]
+    Http,
+    WebSocket,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum BotState {
+    Inactive = 0,
+    Active = 1,
+    Paused = 2,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bot {
+    pub id: Uuid,
+    pub bot_user_id: Uuid,
+    pub description: String,
+    pub developer_id: Uuid,
+    pub subscribe_events: Vec<String>,
+    pub mode: BotMode,
+    pub state: BotState,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+pub type Bots = Vec<Bot>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BotTokens {
+    pub verification_token: String,
+    pub access_token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BotDetail {
+    pub id: Uuid,
+    pub bot_user_id: Uuid,
+    pub description: String,
+    pub developer_id: Uuid,
+    pub subscribe_events: Vec<String>,
+    pub mode: BotMode,
+    pub state: BotState,
+    pub created_at: String,
+    pub updated_at: String,
+    pub tokens: BotTokens,
+    pub endpoint: String,
+    pub privileged: bool,
+    pub channels: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BotEventLog {
+    pub bot_id: Uuid,
+    pub request_id: Uuid,
+    pub event: String,
+    pub result: String,
+    pub code: i32,
+    pub datetime: String,
+}
+
+pub type BotEventLogs = Vec<BotEventLog>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostBotRequest {
+    pub name: String,
+    pub display_name: String,
+    pub description: String,
+    pub mode: BotMode,
+    /// required for `BotMode::Http`
+    pub endpoint: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchBotRequest {
+    pub display_name: Option<String>,
+    pub description: Option<String>,
+    pub privileged: Option<bool>,
+    pub mode: Option<BotMode>,
+    pub endpoint: Option<String>,
+    pub developer_id: Option<Uuid>,
+    pub subscribe_events: Option<Vec<String>>,
+    pub bio: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostBotActionJoinRequest {
+    pub channel_id: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostBotActionLeaveRequest {
+    pub channel_id: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    pub id: Uuid,
+    pub bot_user_id: Uuid,
+    pub display_name: String,
+    pub description: String,
+    pub secure: bool,
+    pub channel_id: Uuid,
+    pub owner_id: Uuid,
+    pub updated_at: String,
+}
+
+pub type Webhooks = Vec<Webhook>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostWebhookRequest {
+    pub name: String,
+    pub description: String,
+    pub channel_id: Uuid,
+    /// empty for an insecure webhook
+    pub secret: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchWebhookRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub channel_id: Option<Uuid>,
+    pub secret: Option<String>,
+    pub owner_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserGroupMember {
+    pub id: Uuid,
+    pub role: String,
+}
+
+pub type UserGroupMembers = Vec<UserGroupMember>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserGroup {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub group_type: String,
+    pub icon: Uuid,
+    pub members: UserGroupMembers,
+    pub created_at: String,
+    pub updated_at: String,
+    pub admins: Vec<Uuid>,
+}
+
+pub type UserGroups = Vec<UserGroup>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostUserGroupRequest {
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub group_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchUserGroupRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "type")]
+    pub group_type: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostUserGroupMemberRequest {
+    pub id: Uuid,
+    pub role: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchUserGroupMemberRequest {
+    pub role: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostUserGroupAdminRequest {
+    pub id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThumbnailType {
+    Image,
+    Waveform,
+}
+
+impl ThumbnailType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Image => "image",
+            Self::Waveform => "waveform",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThumbnailInfo {
+    #[serde(rename = "type")]
+    pub thumbnail_type: ThumbnailType,
+    pub mime: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileInfo {
+    pub id: Uuid,
+    pub name: String,
+    pub mime: String,
+    pub size: i64,
+    pub md5: String,
+    pub is_animated_image: bool,
+    pub created_at: String,
+    pub thumbnails: Vec<ThumbnailInfo>,
+    pub channel_id: Option<Uuid>,
+    pub uploader_id: Option<Uuid>,
+}
+
+pub type FileInfos = Vec<FileInfo>;
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PutUserPasswordRequest {
     pub password: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutMyPasswordRequest {
+    pub password: String,
+    pub new_password: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchMeRequest {
+    pub display_name: Option<String>,
+    pub twitter_id: Option<String>,
+    pub bio: Option<String>,
+    pub home_channel: Option<Uuid>,
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PostUserRequest {
@@ -162,6 +563,413 @@ pub struct StampHistoryEntry {
 
 pub type StampHistoryEntries = Vec<StampHistoryEntry>;
 
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Stamp {
+    pub id: Uuid,
+    pub name: String,
+    pub creator_id: Uuid,
+    pub created_at: String,
+    pub updated_at: String,
+    pub file_id: Uuid,
+    pub is_unicode: bool,
+}
+
+pub type Stamps = Vec<Stamp>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StampStats {
+    pub count: i64,
+    pub total_count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchStampRequest {
+    pub name: Option<String>,
+    pub creator_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StampPalette {
+    pub id: Uuid,
+    pub name: String,
+    pub stamps: Vec<Uuid>,
+    pub creator_id: Uuid,
+    pub created_at: String,
+    pub updated_at: String,
+    pub description: String,
+}
+
+pub type StampPalettes = Vec<StampPalette>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostStampPaletteRequest {
+    pub name: String,
+    pub description: String,
+    pub stamps: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchStampPaletteRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub stamps: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Channel {
+    pub id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub archived: bool,
+    pub force: bool,
+    pub topic: String,
+    pub name: String,
+    pub children: Vec<Uuid>,
+}
+
+pub type Channels = Vec<Channel>;
+
+pub type DMChannel = DirectMessageChannel;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelList {
+    pub public: Channels,
+    /// present only when requested with `include-dm=true`
+    pub dm: Option<Vec<DMChannel>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum ChannelSubscribeLevel {
+    None = 0,
+    Subscribed = 1,
+    Notified = 2,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSubscribeState {
+    pub channel_id: Uuid,
+    pub level: ChannelSubscribeLevel,
+}
+
+pub type UserSubscribeStates = Vec<UserSubscribeState>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutChannelSubscribeLevelRequest {
+    pub level: ChannelSubscribeLevel,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutChannelSubscribersRequest {
+    pub on: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchChannelSubscribersRequest {
+    pub on: Vec<Uuid>,
+    pub off: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnreadChannel {
+    pub channel_id: Uuid,
+    pub count: i32,
+    pub noticeable: bool,
+    pub since: String,
+    pub updated_at: String,
+    pub oldest_message_id: Uuid,
+}
+
+pub type UnreadChannels = Vec<UnreadChannel>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChannelViewState {
+    None,
+    Monitoring,
+    Editing,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MyChannelViewState {
+    pub key: String,
+    pub channel_id: Uuid,
+    pub state: ChannelViewState,
+}
+
+pub type MyChannelViewStates = Vec<MyChannelViewState>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostStarRequest {
+    pub channel_id: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelTopic {
+    pub topic: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutChannelTopicRequest {
+    pub topic: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelStatsStamp {
+    pub id: Uuid,
+    pub count: i64,
+    pub total: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelStatsUser {
+    pub id: Uuid,
+    pub message_count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelStats {
+    pub total_message_count: i64,
+    pub stamps: Vec<ChannelStatsStamp>,
+    pub users: Vec<ChannelStatsUser>,
+    pub datetime: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelViewer {
+    pub user_id: Uuid,
+    pub state: ChannelViewState,
+    pub updated_at: String,
+}
+
+pub type ChannelViewers = Vec<ChannelViewer>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BotUser {
+    pub id: Uuid,
+    pub bot_user_id: Uuid,
+}
+
+pub type BotUsers = Vec<BotUser>;
+
+/// `detail` of a `ChannelEvent`, tagged by its `type`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChannelEventDetail {
+    TopicChanged {
+        user_id: Uuid,
+        before: String,
+        after: String,
+    },
+    SubscribersChanged {
+        user_id: Uuid,
+        on: Vec<Uuid>,
+        off: Vec<Uuid>,
+    },
+    PinAdded {
+        user_id: Uuid,
+        message_id: Uuid,
+    },
+    PinRemoved {
+        user_id: Uuid,
+        message_id: Uuid,
+    },
+    NameChanged {
+        user_id: Uuid,
+        before: String,
+        after: String,
+    },
+    ParentChanged {
+        user_id: Uuid,
+        before: Uuid,
+        after: Uuid,
+    },
+    VisibilityChanged {
+        user_id: Uuid,
+        visibility: bool,
+    },
+    ForcedNotificationChanged {
+        user_id: Uuid,
+        force: bool,
+    },
+    ChildCreated {
+        user_id: Uuid,
+        channel_id: Uuid,
+    },
+    /// an event type this crate doesn't know yet
+    Unknown {
+        event_type: String,
+        detail: serde_json::Value,
+    },
+}
+
+/// wire form of the known `ChannelEventDetail` variants; serde matches it against the
+/// public enum, so a variant missing here is a compile error rather than a silent `Unknown`
+#[derive(Deserialize, Serialize)]
+#[serde(remote = "ChannelEventDetail", tag = "type", content = "detail")]
+enum ChannelEventDetailDef {
+    #[serde(rename_all = "camelCase")]
+    TopicChanged {
+        user_id: Uuid,
+        before: String,
+        after: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    SubscribersChanged {
+        user_id: Uuid,
+        on: Vec<Uuid>,
+        off: Vec<Uuid>,
+    },
+    #[serde(rename_all = "camelCase")]
+    PinAdded { user_id: Uuid, message_id: Uuid },
+    #[serde(rename_all = "camelCase")]
+    PinRemoved { user_id: Uuid, message_id: Uuid },
+    #[serde(rename_all = "camelCase")]
+    NameChanged {
+        user_id: Uuid,
+        before: String,
+        after: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    ParentChanged {
+        user_id: Uuid,
+        before: Uuid,
+        after: Uuid,
+    },
+    #[serde(rename_all = "camelCase")]
+    VisibilityChanged { user_id: Uuid, visibility: bool },
+    #[serde(rename_all = "camelCase")]
+    ForcedNotificationChanged { user_id: Uuid, force: bool },
+    #[serde(rename_all = "camelCase")]
+    ChildCreated { user_id: Uuid, channel_id: Uuid },
+    #[serde(skip)]
+    Unknown {
+        event_type: String,
+        detail: serde_json::Value,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "RawChannelEvent")]
+pub struct ChannelEvent {
+    pub detail: ChannelEventDetail,
+    pub date_time: String,
+}
+
+/// wire form of `ChannelEvent`, so that unknown types fall back to `ChannelEventDetail::Unknown`
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawChannelEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    date_time: String,
+    detail: serde_json::Value,
+}
+
+impl TryFrom<RawChannelEvent> for ChannelEvent {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawChannelEvent) -> Result<Self, Self::Error> {
+        let RawChannelEvent {
+            event_type,
+            date_time,
+            detail,
+        } = raw;
+        let tagged = serde_json::json!({ "type": event_type, "detail": detail });
+        let detail = match ChannelEventDetailDef::deserialize(tagged) {
+            Ok(detail) => detail,
+            // serde reports a tag matching none of the variants this way
+            Err(e) if e.to_string().starts_with("unknown variant") => {
+                ChannelEventDetail::Unknown { event_type, detail }
+            }
+            Err(e) => return Err(e),
+        };
+        Ok(Self { detail, date_time })
+    }
+}
+
+impl Serialize for ChannelEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        /// known variants, written as `type` and `detail` next to `dateTime`
+        struct Known<'a>(&'a ChannelEventDetail);
+
+        impl Serialize for Known<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ChannelEventDetailDef::serialize(self.0, serializer)
+            }
+        }
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct KnownChannelEvent<'a> {
+            #[serde(flatten)]
+            detail: Known<'a>,
+            date_time: &'a str,
+        }
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct UnknownChannelEvent<'a> {
+            #[serde(rename = "type")]
+            event_type: &'a str,
+            date_time: &'a str,
+            detail: &'a serde_json::Value,
+        }
+
+        match &self.detail {
+            ChannelEventDetail::Unknown { event_type, detail } => UnknownChannelEvent {
+                event_type,
+                date_time: &self.date_time,
+                detail,
+            }
+            .serialize(serializer),
+            detail => KnownChannelEvent {
+                detail: Known(detail),
+                date_time: &self.date_time,
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+pub type ChannelEvents = Vec<ChannelEvent>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostChannelRequest {
+    pub name: String,
+    pub parent: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchChannelRequest {
+    pub name: Option<String>,
+    pub archived: Option<bool>,
+    pub force: Option<bool>,
+    pub parent: Option<Uuid>,
+}
+
 #[derive(Debug, Clone)]
 pub enum QrCode {
     Image(Image),
@@ -272,6 +1080,126 @@ pub enum UserPermission {
 
 pub type UserPermissions = Vec<UserPermission>;
 
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginSession {
+    pub id: Uuid,
+    pub issued_at: String,
+}
+
+pub type LoginSessions = Vec<LoginSession>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuth2Scope {
+    Openid,
+    Profile,
+    Read,
+    Write,
+    ManageBot,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2Client {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub developer_id: Uuid,
+    pub scopes: Vec<OAuth2Scope>,
+}
+
+pub type OAuth2Clients = Vec<OAuth2Client>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2ClientDetail {
+    pub id: String,
+    pub developer_id: Uuid,
+    pub description: String,
+    pub name: String,
+    pub scopes: Vec<OAuth2Scope>,
+    pub callback_url: String,
+    pub secret: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostClientRequest {
+    pub name: String,
+    pub callback_url: String,
+    pub scopes: Vec<OAuth2Scope>,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchClientRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub callback_url: Option<String>,
+    pub developer_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveOAuth2Token {
+    pub id: Uuid,
+    pub client_id: String,
+    pub scopes: Vec<OAuth2Scope>,
+    pub issued_at: String,
+}
+
+pub type ActiveOAuth2Tokens = Vec<ActiveOAuth2Token>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct OIDCTraqUserInfo {
+    pub bio: String,
+    pub groups: Vec<Uuid>,
+    pub tags: UserTags,
+    pub last_online: Option<String>,
+    pub twitter_id: String,
+    pub display_name: String,
+    pub icon_file_id: Uuid,
+    pub bot: bool,
+    pub state: UserAccountState,
+    pub permissions: UserPermissions,
+    pub home_channel: Option<Uuid>,
+}
+
+/// fields are snake_case as defined by OpenID Connect
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct OIDCUserInfo {
+    pub sub: String,
+    pub name: String,
+    pub preferred_username: String,
+    pub picture: String,
+    pub updated_at: i64,
+    pub traq: OIDCTraqUserInfo,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalProviderUser {
+    pub provider_name: String,
+    pub linked_at: String,
+    pub external_name: String,
+}
+
+pub type ExternalProviderUsers = Vec<ExternalProviderUser>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostLinkExternalAccount {
+    pub provider_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostUnlinkExternalAccount {
+    pub provider_name: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MyUserDetail {
@@ -290,3 +1218,151 @@ pub struct MyUserDetail {
     pub permissions: UserPermissions,
     pub home_channel: Option<Uuid>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_json(state: u8) -> String {
+        format!(
+            r#"{{
+                "id": "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+                "name": "user",
+                "displayName": "User",
+                "iconFileId": "3fa85f64-5717-4562-b3fc-2c963f66afa7",
+                "bot": false,
+                "state": {},
+                "updatedAt": "2024-01-01T00:00:00Z"
+            }}"#,
+            state
+        )
+    }
+
+    fn user_detail_json(state: u8) -> String {
+        format!(
+            r#"{{
+                "id": "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+                "state": {},
+                "bot": false,
+                "iconFileId": "3fa85f64-5717-4562-b3fc-2c963f66afa7",
+                "displayName": "User",
+                "name": "user",
+                "twitterId": "",
+                "lastOnline": null,
+                "updatedAt": "2024-01-01T00:00:00Z",
+                "tags": [],
+                "groups": [],
+                "bio": "",
+                "homeChannel": null
+            }}"#,
+            state
+        )
+    }
+
+    #[test]
+    fn user_state_from_number() {
+        let expected = [
+            UserAccountState::Deactivated,
+            UserAccountState::Active,
+            UserAccountState::Suspended,
+        ];
+        for (n, state) in expected.into_iter().enumerate() {
+            let user: User = serde_json::from_str(&user_json(n as u8)).unwrap();
+            assert_eq!(user.state, state);
+            let detail: UserDetail = serde_json::from_str(&user_detail_json(n as u8)).unwrap();
+            assert_eq!(detail.state, state);
+            assert_eq!(user.is_active(), n == 1);
+            assert_eq!(detail.is_suspended(), n == 2);
+        }
+    }
+
+    #[test]
+    fn user_state_rejects_unknown_number() {
+        assert!(serde_json::from_str::<User>(&user_json(3)).is_err());
+        assert!(serde_json::from_str::<UserDetail>(&user_detail_json(3)).is_err());
+    }
+
+    #[test]
+    fn channel_events() {
+        let json = r#"[
+            {"type": "TopicChanged", "dateTime": "2024-01-01T00:00:00.000Z", "detail": {"userId": "00000000-0000-0000-0000-000000000001", "before": "old", "after": "new"}},
+            {"type": "SubscribersChanged", "dateTime": "2024-01-01T00:00:01.000Z", "detail": {"userId": "00000000-0000-0000-0000-000000000001", "on": ["00000000-0000-0000-0000-000000000002"], "off": []}},
+            {"type": "PinAdded", "dateTime": "2024-01-01T00:00:02.000Z", "detail": {"userId": "00000000-0000-0000-0000-000000000001", "messageId": "00000000-0000-0000-0000-000000000003"}},
+            {"type": "PinRemoved", "dateTime": "2024-01-01T00:00:03.000Z", "detail": {"userId": "00000000-0000-0000-0000-000000000001", "messageId": "00000000-0000-0000-0000-000000000003"}},
+            {"type": "NameChanged", "dateTime": "2024-01-01T00:00:04.000Z", "detail": {"userId": "00000000-0000-0000-0000-000000000001", "before": "general", "after": "random"}},
+            {"type": "ParentChanged", "dateTime": "2024-01-01T00:00:05.000Z", "detail": {"userId": "00000000-0000-0000-0000-000000000001", "before": "00000000-0000-0000-0000-000000000004", "after": "00000000-0000-0000-0000-000000000005"}},
+            {"type": "VisibilityChanged", "dateTime": "2024-01-01T00:00:06.000Z", "detail": {"userId": "00000000-0000-0000-0000-000000000001", "visibility": false}},
+            {"type": "ForcedNotificationChanged", "dateTime": "2024-01-01T00:00:07.000Z", "detail": {"userId": "00000000-0000-0000-0000-000000000001", "force": true}},
+            {"type": "ChildCreated", "dateTime": "2024-01-01T00:00:08.000Z", "detail": {"userId": "00000000-0000-0000-0000-000000000001", "channelId": "00000000-0000-0000-0000-000000000006"}},
+            {"type": "ArchiveChanged", "dateTime": "2024-01-01T00:00:09.000Z", "detail": {"userId": "00000000-0000-0000-0000-000000000001", "archived": true}}
+        ]"#;
+        let user_id = Uuid::from_u128(1);
+        let expected = [
+            ChannelEventDetail::TopicChanged {
+                user_id,
+                before: "old".to_string(),
+                after: "new".to_string(),
+            },
+            ChannelEventDetail::SubscribersChanged {
+                user_id,
+                on: vec![Uuid::from_u128(2)],
+                off: vec![],
+            },
+            ChannelEventDetail::PinAdded {
+                user_id,
+                message_id: Uuid::from_u128(3),
+            },
+            ChannelEventDetail::PinRemoved {
+                user_id,
+                message_id: Uuid::from_u128(3),
+            },
+            ChannelEventDetail::NameChanged {
+                user_id,
+                before: "general".to_string(),
+                after: "random".to_string(),
+            },
+            ChannelEventDetail::ParentChanged {
+                user_id,
+                before: Uuid::from_u128(4),
+                after: Uuid::from_u128(5),
+            },
+            ChannelEventDetail::VisibilityChanged {
+                user_id,
+                visibility: false,
+            },
+            ChannelEventDetail::ForcedNotificationChanged {
+                user_id,
+                force: true,
+            },
+            ChannelEventDetail::ChildCreated {
+                user_id,
+                channel_id: Uuid::from_u128(6),
+            },
+            ChannelEventDetail::Unknown {
+                event_type: "ArchiveChanged".to_string(),
+                detail: serde_json::json!({
+                    "userId": "00000000-0000-0000-0000-000000000001",
+                    "archived": true,
+                }),
+            },
+        ];
+
+        let events: ChannelEvents = serde_json::from_str(json).unwrap();
+        assert_eq!(events.len(), expected.len());
+        for (i, (event, detail)) in events.iter().zip(expected).enumerate() {
+            assert_eq!(event.detail, detail);
+            assert_eq!(event.date_time, format!("2024-01-01T00:00:0{}.000Z", i));
+        }
+
+        let round_trip: serde_json::Value = serde_json::to_value(&events).unwrap();
+        let original: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(round_trip, original);
+    }
+
+    #[test]
+    fn malformed_known_channel_event_is_an_error() {
+        let json = r#"{"type": "TopicChanged", "dateTime": "2024-01-01T00:00:00.000Z", "detail": {"userId": "00000000-0000-0000-0000-000000000001", "before": "old"}}"#;
+        let err = serde_json::from_str::<ChannelEvent>(json).unwrap_err();
+        assert!(err.to_string().contains("after"));
+    }
+}
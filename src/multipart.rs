@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+
+/// Deterministic so that dry-run and curl output stay stable between runs;
+/// a numeric suffix is appended while any part contains it
+const BOUNDARY: &str = "hyper-traq-multipart-boundary-7b6f2c9e4d1a";
+
+#[derive(Debug, Clone)]
+struct Part<'a> {
+    header: String,
+    data: Cow<'a, [u8]>,
+}
+
+/// Minimal `multipart/form-data` body writer
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Multipart<'a> {
+    parts: Vec<Part<'a>>,
+}
+
+impl<'a> Multipart<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary())
+    }
+
+    pub fn text(mut self, name: &str, value: &str) -> Self {
+        let header = format!(
+            "Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+            escape(name)
+        );
+        self.parts.push(Part {
+            header,
+            data: Cow::Owned(value.as_bytes().to_vec()),
+        });
+        self
+    }
+
+    pub fn file(mut self, name: &str, filename: &str, content_type: &str, data: &'a [u8]) -> Self {
+        let header = format!(
+            "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+            escape(name),
+            escape(filename),
+            escape(content_type)
+        );
+        self.parts.push(Part {
+            header,
+            data: Cow::Borrowed(data),
+        });
+        self
+    }
+
+    pub fn finish(&self) -> Vec<u8> {
+        let boundary = self.boundary();
+        let mut body = vec![];
+        for part in &self.parts {
+            body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+            body.extend_from_slice(part.header.as_bytes());
+            body.extend_from_slice(&part.data);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+        body
+    }
+
+    fn boundary(&self) -> String {
+        let contains = |boundary: &str| {
+            self.parts.iter().any(|part| {
+                part.header.contains(boundary)
+                    || part
+                        .data
+                        .windows(boundary.len())
+                        .any(|w| w == boundary.as_bytes())
+            })
+        };
+        let mut boundary = BOUNDARY.to_string();
+        let mut n = 0;
+        while contains(&boundary) {
+            n += 1;
+            boundary = format!("{}-{}", BOUNDARY, n);
+        }
+        boundary
+    }
+}
+
+/// Percent-encodes `"`, CR and LF, which would otherwise break out of a quoted header value
+fn escape(s: &str) -> String {
+    s.replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_names() {
+        let form = Multipart::new().file("fi\"le", "a\"\r\n.txt", "text/plain", b"x");
+        let body = String::from_utf8(form.finish()).unwrap();
+        assert!(body.contains(
+            "Content-Disposition: form-data; name=\"fi%22le\"; filename=\"a%22%0D%0A.txt\"\r\n"
+        ));
+    }
+
+    #[test]
+    fn boundary_avoids_data() {
+        let data = format!("--{}\r\n--{}-1", BOUNDARY, BOUNDARY);
+        let form = Multipart::new().text("a", &data);
+        let boundary = format!("{}-2", BOUNDARY);
+        assert_eq!(
+            form.content_type(),
+            format!("multipart/form-data; boundary={}", boundary)
+        );
+        assert_eq!(
+            form.finish(),
+            format!(
+                "--{b}\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\n{}\r\n--{b}--\r\n",
+                data,
+                b = boundary
+            )
+            .into_bytes()
+        );
+    }
+}